@@ -1,4 +1,4 @@
-use utils::{get_input_path, read_lines};
+use utils::{lines, log, Diagnostic, Part, Result, Solver};
 
 const DIGIT_TUPLES: [(&str, &str); 9] = [
     ("one", "1"),
@@ -18,7 +18,9 @@ struct Occurrence {
     digit_val: String,
 }
 
-fn replace_digit_str(line: String) -> String {
+/// `line` with the digits spelled out as words replaced by the digits
+/// themselves, scanning from left to right.
+pub fn replace_digit_str(line: &str) -> String {
     let mut occurrences: Vec<_> = DIGIT_TUPLES.iter()
         .flat_map(|&(digit_str, digit_val)| {
             line.match_indices(digit_str)
@@ -56,8 +58,7 @@ fn replace_digit_str(line: String) -> String {
         }
     });
 
-    let repl_str = scan_state.curr_str + &line[scan_state.curr_index..];
-    return repl_str;
+    scan_state.curr_str + &line[scan_state.curr_index..]
 
 
 
@@ -76,51 +77,70 @@ fn replace_digit_str(line: String) -> String {
     //println!("{}", &repl_line);
 }
 
-fn main() {
-    let input_path = get_input_path(1, Some("input.txt"));
-
-    if let Ok(lines) = read_lines(&input_path) {
-        let calibrations: Vec<_> = lines
-            .map(|line| line.unwrap())
-            .map(|line| (String::from(&line), replace_digit_str(String::from(line))))
-            .map(|(line, repl_line)| {
-                (
-                    String::from(&line),
-                    String::from(&repl_line),
-                    get_calibration(&repl_line),
-                )
-            })
-            .collect();
-        // .map(|line| line.unwrap())
-        // .map(|line| replace_digit_str(line))
-        // .map(|line| get_calibration(&line))
-        // .collect();
-
-        // for ele in calibrations.iter() {
-        //     println!("{} => {} = {}", &ele.0, &ele.1, &ele.2)
-        // }
-
-        let sum: u32 = calibrations.iter().map(|vals| vals.2).sum();
-        println!("The sum is {}", sum)
-    } else {
-        println!("File cannot be found")
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 1;
+
+    /// The whole document, split into lines only when solving, so that
+    /// parsing copies it once rather than line by line.
+    type Input = String;
+    type Answer = u32;
+
+    fn parse(&self, input: &str) -> Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(&self, document: &String) -> Result<u32> {
+        Ok(get_calibrations(document, false)?.into_iter().sum())
+    }
+
+    fn part_two(&self, document: &String) -> Result<u32> {
+        Ok(get_calibrations(document, true)?.into_iter().sum())
+    }
+
+    fn diagnostics(&self, document: &String, part: Part) -> Result<Vec<Diagnostic>> {
+        let calibrations = get_calibrations(document, part == Part::Two)?;
+
+        Ok(vec![Diagnostic::new("calibrations", calibrations)])
     }
 }
 
-fn get_calibration(line: &str) -> u32 {
+/// The calibration of every line, reading spelled-out digits as digits too
+/// when `spelled_out`.
+pub fn get_calibrations(document: &str, spelled_out: bool) -> Result<Vec<u32>> {
+    lines(document)
+        .map(|line| {
+            let calibration = if spelled_out {
+                let repl_line = replace_digit_str(line.as_str());
+                let calibration = get_calibration(&repl_line);
+
+                log::trace!("{} => {} = {:?}", line, repl_line, calibration);
+                calibration
+            } else {
+                get_calibration(line.as_str())
+            };
+
+            calibration.ok_or_else(|| line.error("Cannot find digits"))
+        })
+        .collect()
+}
+
+/// The number made of the first and last digit of `line`, if it has any.
+pub fn get_calibration(line: &str) -> Option<u32> {
     let digits: Vec<u32> = line
         .chars()
         .filter_map(|character| character.to_digit(10))
         .collect();
 
     if let (Some(first_digit), Some(last_digit)) = (digits.first(), digits.last()) {
-        return (*first_digit * 10 + *last_digit) as u32;
+        Some(*first_digit * 10 + *last_digit)
     } else {
-        panic!("Cannot find digits");
+        None
     }
 }
 
-// fn get_calibration(line: &str) -> u32 {
+// fn get_calibration(line: &str) -> Option<u32> {
 //     let mut digits = Vec::<u32>::new();
 
 //     let mut digit_buf = String::new();
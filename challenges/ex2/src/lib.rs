@@ -0,0 +1,151 @@
+use utils::{lines, parse, Diagnostic, Error, Part, Result, Solver, Span};
+
+/// How many balls of each color were drawn at once.
+pub struct BallCount {
+    pub blue: u32,
+    pub red: u32,
+    pub green: u32,
+}
+
+impl BallCount {
+    fn new() -> BallCount {
+        BallCount {
+            blue: 0,
+            red: 0,
+            green: 0,
+        }
+    }
+
+    /// Whether these balls could be drawn from a bag holding `max_count`.
+    pub fn is_possible(&self, max_count: &BallCount) -> bool {
+        self.blue <= max_count.blue && self.green <= max_count.green && self.red <= max_count.red
+    }
+
+    /// The number of red, green and blue balls multiplied together, or
+    /// `None` if that does not fit in a `u32`.
+    pub fn power(&self) -> Option<u32> {
+        self.red.checked_mul(self.green)?.checked_mul(self.blue)
+    }
+}
+
+pub struct Game {
+    pub id: u32,
+    pub ball_counts: Vec<BallCount>,
+}
+
+/// Parses a `Game <id>: <count> <color>, ...; ...` line.
+pub fn parse_line(line: Span) -> Result<Game> {
+    let (id, extractions_str) = parse::numbered::<u32>(line, "Game")?;
+
+    let ball_counts: Vec<_> = parse::nested_list(extractions_str, ";", ",")
+        .into_iter()
+        .map(|extraction| {
+            extraction
+                .into_iter()
+                .try_fold(BallCount::new(), |ball_count, ball_str| {
+                    let (count, color) = parse::count::<u32>(ball_str)?;
+
+                    match color.as_str() {
+                        "red" => Ok(BallCount {
+                            red: count,
+                            ..ball_count
+                        }),
+                        "blue" => Ok(BallCount {
+                            blue: count,
+                            ..ball_count
+                        }),
+                        "green" => Ok(BallCount {
+                            green: count,
+                            ..ball_count
+                        }),
+                        _ => Err(color.error(format!("Unknown color '{}'", color))),
+                    }
+                })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Game {
+        id,
+        ball_counts,
+    })
+}
+
+/// The bag that part one asks about.
+pub const MAX_COUNT: BallCount = BallCount {
+    red: 12,
+    green: 13,
+    blue: 14
+};
+
+impl Game {
+    /// Whether every draw of the game fits in a bag holding `max_count`.
+    pub fn is_possible(&self, max_count: &BallCount) -> bool {
+        self.ball_counts.iter().all(|ball_count| ball_count.is_possible(max_count))
+    }
+
+    /// The fewest balls of each color the bag must have held.
+    pub fn min_viable_count(&self) -> BallCount {
+        let max = |color: fn(&BallCount) -> u32| self.ball_counts.iter().map(color).max().unwrap_or(0);
+
+        BallCount {
+            red: max(|count| count.red),
+            green: max(|count| count.green),
+            blue: max(|count| count.blue),
+        }
+    }
+}
+
+/// The ids of the games that are possible with [`MAX_COUNT`].
+pub fn possible_games(games: &[Game]) -> Vec<u32> {
+    games
+        .iter()
+        .filter(|game| game.is_possible(&MAX_COUNT))
+        .map(|game| game.id)
+        .collect()
+}
+
+/// The power of the [fewest balls](Game::min_viable_count) of every game.
+pub fn powers(games: &[Game]) -> Result<Vec<u32>> {
+    games
+        .iter()
+        .map(|game| game.min_viable_count().power().ok_or_else(too_large))
+        .collect()
+}
+
+fn too_large() -> Error {
+    Error::new("the total does not fit in 32 bits")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+    type Answer = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Game>> {
+        lines(input).map(parse_line).collect()
+    }
+
+    fn part_one(&self, games: &Vec<Game>) -> Result<u32> {
+        possible_games(games)
+            .into_iter()
+            .try_fold(0_u32, u32::checked_add)
+            .ok_or_else(too_large)
+    }
+
+    fn part_two(&self, games: &Vec<Game>) -> Result<u32> {
+        powers(games)?
+            .into_iter()
+            .try_fold(0_u32, u32::checked_add)
+            .ok_or_else(too_large)
+    }
+
+    fn diagnostics(&self, games: &Vec<Game>, part: Part) -> Result<Vec<Diagnostic>> {
+        Ok(match part {
+            Part::One => vec![Diagnostic::new("possible_games", possible_games(games))],
+            Part::Two => vec![Diagnostic::new("powers", powers(games)?)],
+        })
+    }
+}
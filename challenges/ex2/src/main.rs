@@ -0,0 +1,8 @@
+use std::process::ExitCode;
+
+use ex2::Solution;
+use utils::run_main;
+
+fn main() -> ExitCode {
+    run_main(&Solution)
+}
@@ -0,0 +1,157 @@
+use std::collections::BTreeMap;
+use std::fmt::Display;
+
+use itertools::Itertools;
+use utils::{log, Diagnostic, Error, Grid, Part, Position, Result, Run, Solver};
+
+/// A number of the schematic and the cells it spans.
+#[derive(Clone, Hash, Eq, PartialEq, Debug)]
+pub struct Number {
+    pub run: Run,
+    pub value: u32,
+}
+
+pub struct Schematic {
+    pub grid: Grid<char>,
+    pub numbers: Vec<Number>,
+}
+
+impl Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "([{}:{}]@{}: {})",
+            self.run.cols.start,
+            self.run.cols.end - 1,
+            self.run.row,
+            self.value
+        )
+    }
+}
+
+/// Whether `cell` holds a symbol, which is anything but a digit or a `.`.
+pub fn is_symbol(cell: char) -> bool {
+    !cell.is_ascii_digit() && cell != '.'
+}
+
+/// Reads the schematic grid and the numbers written in it.
+pub fn parse_schematic(input: &str) -> Result<Schematic> {
+    let grid = Grid::from_text(input)?;
+
+    let numbers = grid
+        .runs(|cell| cell.is_ascii_digit())
+        .into_iter()
+        .map(|run| {
+            let digits: String = grid.run_cells(&run).iter().collect();
+            let value = digits.parse::<u32>().map_err(|err| {
+                let message = format!("invalid number '{}': {}", digits, err);
+                Error::new(message).at(run.row + 1, run.cols.start + 1)
+            })?;
+
+            Ok(Number { run, value })
+        })
+        .collect::<Result<_>>()?;
+
+    Ok(Schematic { grid, numbers })
+}
+
+/// The numbers touching a symbol, in reading order.
+pub fn find_part_numbers(schematic: &Schematic) -> Vec<&Number> {
+    let grid = &schematic.grid;
+
+    schematic
+        .numbers
+        .iter()
+        .filter(|number| {
+            grid.run_neighbours(&number.run)
+                .any(|position| is_symbol(grid[position]))
+        })
+        .collect_vec()
+}
+
+/// Pairs of numbers sharing a `*` that touches exactly two numbers, in the
+/// order the gears appear in the schematic.
+pub fn find_gear_numbers(schematic: &Schematic) -> Vec<(&Number, &Number)> {
+    let grid = &schematic.grid;
+    let mut adjacents: BTreeMap<Position, Vec<&Number>> = BTreeMap::new();
+
+    for number in &schematic.numbers {
+        for position in grid.run_neighbours(&number.run) {
+            if grid[position] == '*' {
+                adjacents.entry(position).or_default().push(number);
+            }
+        }
+    }
+
+    adjacents
+        .into_values()
+        .filter_map(|numbers| numbers.into_iter().collect_tuple())
+        .collect_vec()
+}
+
+/// The product of the two numbers of every gear.
+pub fn gear_ratios(gear_numbers: &[(&Number, &Number)]) -> Result<Vec<u32>> {
+    gear_numbers
+        .iter()
+        .map(|(a, b)| a.value.checked_mul(b.value).ok_or_else(too_large))
+        .collect()
+}
+
+fn too_large() -> Error {
+    Error::new("the total does not fit in 32 bits")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 3;
+
+    type Input = Schematic;
+    type Answer = u32;
+
+    fn parse(&self, input: &str) -> Result<Schematic> {
+        parse_schematic(input)
+    }
+
+    fn part_one(&self, schematic: &Schematic) -> Result<u32> {
+        let part_numbers = find_part_numbers(schematic);
+
+        log::debug!("part numbers: {}", part_numbers.iter().join(", "));
+
+        part_numbers
+            .into_iter()
+            .try_fold(0_u32, |sum, number| sum.checked_add(number.value))
+            .ok_or_else(too_large)
+    }
+
+    fn part_two(&self, schematic: &Schematic) -> Result<u32> {
+        let gear_numbers = find_gear_numbers(schematic);
+
+        log::debug!(
+            "gear numbers: [{}]",
+            gear_numbers
+                .iter()
+                .map(|(a, b)| format!("({}, {})", a, b))
+                .join(", ")
+        );
+
+        gear_ratios(&gear_numbers)?
+            .into_iter()
+            .try_fold(0_u32, u32::checked_add)
+            .ok_or_else(too_large)
+    }
+
+    fn diagnostics(&self, schematic: &Schematic, part: Part) -> Result<Vec<Diagnostic>> {
+        if part == Part::Two {
+            let gear_ratios = gear_ratios(&find_gear_numbers(schematic))?;
+            return Ok(vec![Diagnostic::new("gear_ratios", gear_ratios)]);
+        }
+
+        let part_numbers = find_part_numbers(schematic)
+            .into_iter()
+            .map(|number| number.value)
+            .collect_vec();
+
+        Ok(vec![Diagnostic::new("part_numbers", part_numbers)])
+    }
+}
@@ -0,0 +1,117 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use utils::{lines, parse, Diagnostic, Error, Part, Result, Solver, Span};
+
+pub struct Card {
+    pub id: u32,
+    pub winning: Vec<u32>,
+    pub scratched: Vec<u32>, // sorted
+}
+
+impl Card {
+    /// How many of the numbers you have are winning numbers.
+    pub fn get_n_matches(&self) -> u32 {
+        let matches = self
+            .winning
+            .iter()
+            .filter(|winning_n| self.scratched.contains(winning_n))
+            .collect_vec();
+
+        matches.len().try_into().unwrap()
+    }
+
+    /// One point for the first match, doubled for every other one.
+    pub fn get_points(&self) -> Result<u32> {
+        let n_matches = self.get_n_matches();
+        if n_matches > 0 {
+            2_u32
+                .checked_pow(n_matches - 1)
+                .ok_or_else(|| Error::new(format!("card {} is worth too many points", self.id)))
+        } else {
+            Ok(0)
+        }
+    }
+}
+
+/// Parses a `Card <id>: <winning numbers> | <numbers you have>` line.
+pub fn parse_line(line: Span) -> Result<Card> {
+    let (card_number, numbers_str) = parse::numbered::<u32>(line, "Card")?;
+    let (winning_str, scratched_str) = numbers_str.split_once("|")?;
+
+    let winning = parse::numbers(winning_str)?;
+    let mut scratched: Vec<u32> = parse::numbers(scratched_str)?;
+
+    scratched.sort();
+
+    Ok(Card {
+        id: card_number,
+        winning,
+        scratched,
+    })
+}
+
+/// [`Card::get_points`] of every card.
+pub fn get_points(cards: &[Card]) -> Result<Vec<u32>> {
+    cards.iter().map(Card::get_points).collect()
+}
+
+/// How many copies of every card end up being scratched, the original
+/// included, when each card wins copies of the cards following it.
+pub fn count_copies(cards: &[Card]) -> Result<Vec<u32>> {
+    let mut counts: HashMap<u32, u32> = HashMap::with_capacity(cards.len());
+
+    for card in cards {
+        counts.insert(card.id, 1_u32);
+    }
+
+    for card in cards {
+        let n_matches = card.get_n_matches();
+
+        let curr_card_count = counts[&card.id];
+
+        for idx in (1..=n_matches).filter_map(|offset| card.id.checked_add(offset)) {
+            if let Some(count) = counts.get_mut(&idx) {
+                *count = count.checked_add(curr_card_count).ok_or_else(too_many)?;
+            }
+        }
+    }
+
+    Ok(cards.iter().map(|card| counts[&card.id]).collect())
+}
+
+fn too_many() -> Error {
+    Error::new("the total does not fit in 32 bits")
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Card>;
+    type Answer = u32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Card>> {
+        lines(input).map(parse_line).collect()
+    }
+
+    fn part_one(&self, cards: &Vec<Card>) -> Result<u32> {
+        get_points(cards)?
+            .into_iter()
+            .try_fold(0_u32, |total, points| total.checked_add(points).ok_or_else(too_many))
+    }
+
+    fn part_two(&self, cards: &Vec<Card>) -> Result<u32> {
+        count_copies(cards)?
+            .into_iter()
+            .try_fold(0_u32, |total, count| total.checked_add(count).ok_or_else(too_many))
+    }
+
+    fn diagnostics(&self, cards: &Vec<Card>, part: Part) -> Result<Vec<Diagnostic>> {
+        Ok(match part {
+            Part::One => vec![Diagnostic::new("points", get_points(cards)?)],
+            Part::Two => vec![Diagnostic::new("copies", count_copies(cards)?)],
+        })
+    }
+}
@@ -0,0 +1,302 @@
+use itertools::Itertools;
+use utils::{log, parse, sections, Diagnostic, Error, Part, Result, Solver, Span};
+
+/// Maps `range_size` numbers from `source_start` on to `destination_start` on.
+pub struct Range {
+    pub source_start: u64,
+    pub destination_start: u64,
+    pub range_size: u64,
+}
+
+/// One `x-to-y map` section; numbers outside every range map to themselves.
+pub struct Map {
+    pub ranges: Vec<Range>,
+}
+
+/// Every map, from seeds to locations.
+pub struct Maps {
+    pub seed_to_soil: Map,
+    pub soil_to_fertilizer: Map,
+    pub fertilizer_to_water: Map,
+    pub water_to_light: Map,
+    pub light_to_temperature: Map,
+    pub temperature_to_humidity: Map,
+    pub humidity_to_location: Map,
+}
+
+impl Range {
+    /// Where `seed` maps to, if it is within this range.
+    pub fn process(&self, seed: u64) -> Option<u64> {
+        if (self.source_start..self.source_start + self.range_size).contains(&seed) {
+            let offset = seed - self.source_start;
+            return Some(self.destination_start + offset);
+        }
+        None
+    }
+
+    /// The numbers this range maps.
+    pub fn get_source_range(&self) -> std::ops::Range<u64> {
+        self.source_start..self.source_start + self.range_size
+    }
+}
+
+impl Map {
+    /// Where `seed` maps to.
+    pub fn process(&self, seed: u64) -> u64 {
+        for range in &self.ranges {
+            if let Some(mapped) = range.process(seed) {
+                return mapped;
+            }
+        }
+        seed
+    }
+
+    /// The range mapping `seed`, if any.
+    pub fn get_containing_range(&self, seed: u64) -> Option<&Range> {
+        self.ranges
+            .iter()
+            .find(|range| range.get_source_range().contains(&seed))
+    }
+
+    /// Where the seeds of `seed_range` map to, as ranges of the same total
+    /// size.
+    pub fn process_range(&self, seed_range: &SeedRange) -> Vec<SeedRange> {
+        let mut curr_seed_range = seed_range.clone();
+        let mut new_ranges: Vec<SeedRange> = Vec::new();
+
+        loop {
+            if let Some(containing_range) = self.get_containing_range(curr_seed_range.start) {
+                let next_start = containing_range.process(curr_seed_range.start).unwrap();
+                let available_size = containing_range.range_size
+                    - (curr_seed_range.start - containing_range.source_start);
+                if curr_seed_range.size <= available_size {
+                    new_ranges.push(SeedRange {
+                        start: next_start,
+                        size: curr_seed_range.size,
+                    });
+                    break;
+                } else {
+                    new_ranges.push(SeedRange {
+                        start: next_start,
+                        size: available_size,
+                    });
+
+                    curr_seed_range.start += available_size;
+                    curr_seed_range.size -= available_size;
+                    continue;
+                }
+            } else {
+                // Seeds map to themselves up to the first range starting
+                // inside this one, wherever that range ends.
+                let candidate_end = curr_seed_range.start + curr_seed_range.size;
+                let next_range_start = self
+                    .ranges
+                    .iter()
+                    .map(|range| range.source_start)
+                    .filter(|&start| start > curr_seed_range.start && start < candidate_end)
+                    .min();
+
+                if let Some(next_range_start) = next_range_start {
+                    let new_range = SeedRange {
+                        start: curr_seed_range.start,
+                        size: next_range_start - curr_seed_range.start,
+                    };
+
+                    curr_seed_range.start = next_range_start;
+                    curr_seed_range.size -= new_range.size;
+
+                    new_ranges.push(new_range);
+                } else {
+                    new_ranges.push(curr_seed_range.clone());
+                    break;
+                }
+            }
+        }
+
+        new_ranges
+    }
+}
+
+/// Maps every range of `ranges` through `map`.
+pub fn apply_map_to_ranges(ranges: Vec<SeedRange>, map: &Map) -> Vec<SeedRange> {
+    let mapped_ranges = ranges
+        .iter()
+        .flat_map(|range| map.process_range(range))
+        .collect_vec();
+
+    let mapped_size = mapped_ranges.iter().map(|range| range.size).sum::<u64>();
+    let original_size = ranges.iter().map(|range| range.size).sum::<u64>();
+
+    assert!(mapped_size == original_size);
+
+    mapped_ranges
+}
+
+impl Maps {
+    /// The location of `seed`.
+    pub fn process(&self, seed: u64) -> u64 {
+        let x1 = self.seed_to_soil.process(seed);
+        let x2 = self.soil_to_fertilizer.process(x1);
+        let x3 = self.fertilizer_to_water.process(x2);
+        let x4 = self.water_to_light.process(x3);
+        let x5 = self.light_to_temperature.process(x4);
+        let x6 = self.temperature_to_humidity.process(x5);
+        let x7 = self.humidity_to_location.process(x6);
+
+        log::trace!(
+            "{} s=>s {} s=>f {} f=>w {} w=>l {} l=>t {} t=>h {} h=>l {}",
+            seed, x1, x2, x3, x4, x5, x6, x7
+        );
+
+        x7
+    }
+
+    /// The location ranges of the seeds of `seed_range`.
+    pub fn process_range(&self, seed_range: &SeedRange) -> Vec<SeedRange> {
+        let start_ranges = vec![seed_range.clone()];
+        let x1 = apply_map_to_ranges(start_ranges, &self.seed_to_soil);
+        let x2 = apply_map_to_ranges(x1, &self.soil_to_fertilizer);
+        let x3 = apply_map_to_ranges(x2, &self.fertilizer_to_water);
+        let x4 = apply_map_to_ranges(x3, &self.water_to_light);
+        let x5 = apply_map_to_ranges(x4, &self.light_to_temperature);
+        let x6 = apply_map_to_ranges(x5, &self.temperature_to_humidity);
+        apply_map_to_ranges(x6, &self.humidity_to_location)
+    }
+}
+
+/// Parses the `<destination> <source> <size>` lines of a map section. Both
+/// ends of a range must fit in a `u64`.
+pub fn parse_map(range_lines: &[Span]) -> Result<Map> {
+    /* The line is in format X Y Z */
+    let ranges = range_lines
+        .iter()
+        .map(|range_line| {
+            let [destination_start, source_start, range_size] =
+                parse::numbers_array::<u64, 3>(*range_line)?;
+
+            if source_start.max(destination_start).checked_add(range_size).is_none() {
+                return Err(range_line.error("range ends beyond the largest 64-bit number"));
+            }
+
+            Ok(Range {
+                source_start,
+                destination_start,
+                range_size,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Map { ranges })
+}
+
+/// `size` numbers from `start` on.
+#[derive(Clone)]
+pub struct SeedRange {
+    pub start: u64,
+    pub size: u64,
+}
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Maps,
+}
+
+impl Almanac {
+    /// The seeds read as part two does: pairs of range start and size.
+    pub fn seed_ranges(&self) -> Result<Vec<SeedRange>> {
+        if !self.seeds.len().is_multiple_of(2) {
+            return Err(Error::new("seed ranges must come in start/size pairs"));
+        }
+
+        self.seeds
+            .chunks(2)
+            .enumerate()
+            .map(|(idx, pair)| match pair[0].checked_add(pair[1]) {
+                Some(_) => Ok(SeedRange {
+                    start: pair[0],
+                    size: pair[1],
+                }),
+                None => Err(Error::new(format!(
+                    "seed range {} ends beyond the largest 64-bit number",
+                    idx + 1
+                ))),
+            })
+            .collect()
+    }
+
+    /// The location of every seed.
+    pub fn locations(&self) -> Vec<u64> {
+        self.seeds.iter().map(|&seed| self.maps.process(seed)).collect()
+    }
+
+    /// The lowest location of every [seed range](Almanac::seed_ranges).
+    pub fn lowest_range_locations(&self) -> Result<Vec<u64>> {
+        let lowest = self
+            .seed_ranges()?
+            .iter()
+            .map(|seed_range| {
+                let ranges = self.maps.process_range(seed_range);
+                log::trace!("{} location ranges", ranges.len());
+
+                ranges.iter().map(|range| range.start).min().unwrap_or(seed_range.start)
+            })
+            .collect();
+
+        Ok(lowest)
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 5;
+
+    type Input = Almanac;
+    type Answer = u64;
+
+    fn parse(&self, input: &str) -> Result<Almanac> {
+        let sections = sections(input);
+
+        // Without any number, the seeds line reads as the header of a section.
+        let first = sections.get(0)?;
+        let seeds = match first.name() {
+            Some("seeds") if first.lines().is_empty() => Vec::new(),
+            _ => parse::labeled_numbers::<u64>(first.line(0)?)?.1,
+        };
+
+        let maps = Maps {
+            seed_to_soil: parse_map(sections.named("seed-to-soil map")?.lines())?,
+            soil_to_fertilizer: parse_map(sections.named("soil-to-fertilizer map")?.lines())?,
+            fertilizer_to_water: parse_map(sections.named("fertilizer-to-water map")?.lines())?,
+            water_to_light: parse_map(sections.named("water-to-light map")?.lines())?,
+            light_to_temperature: parse_map(sections.named("light-to-temperature map")?.lines())?,
+            temperature_to_humidity: parse_map(sections.named("temperature-to-humidity map")?.lines())?,
+            humidity_to_location: parse_map(sections.named("humidity-to-location map")?.lines())?,
+        };
+
+        Ok(Almanac { seeds, maps })
+    }
+
+    fn part_one(&self, almanac: &Almanac) -> Result<u64> {
+        almanac
+            .locations()
+            .into_iter()
+            .min()
+            .ok_or_else(|| Error::new("no seeds to plant"))
+    }
+
+    fn part_two(&self, almanac: &Almanac) -> Result<u64> {
+        almanac
+            .lowest_range_locations()?
+            .into_iter()
+            .min()
+            .ok_or_else(|| Error::new("no seed ranges to plant"))
+    }
+
+    fn diagnostics(&self, almanac: &Almanac, part: Part) -> Result<Vec<Diagnostic>> {
+        Ok(match part {
+            Part::One => vec![Diagnostic::new("locations", almanac.locations())],
+            Part::Two => vec![Diagnostic::new("lowest_locations", almanac.lowest_range_locations()?)],
+        })
+    }
+}
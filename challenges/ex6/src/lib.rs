@@ -0,0 +1,156 @@
+use itertools::Itertools;
+use std::iter::zip;
+use utils::{lines, log, parse, Diagnostic, Error, Part, Result, Solver};
+
+/// A race lasting `time` milliseconds, with a record of `distance`.
+pub struct Race {
+    pub time: u64,
+    pub distance: u64,
+}
+
+impl Race {
+    /// The furthest the boat can go, by holding the button for half the race.
+    pub fn max_distance(&self) -> u128 {
+        let hold = self.time / 2;
+        hold as u128 * (self.time - hold) as u128
+    }
+
+    /// Whether some way of holding the button beats the record.
+    pub fn can_be_won(&self) -> bool {
+        self.max_distance() > self.distance as u128
+    }
+
+    /// How many ways of holding the button beat the record distance, none
+    /// for a race that [cannot be won](Race::can_be_won).
+    pub fn get_victory_range_size(&self) -> u64 {
+        if !self.can_be_won() {
+            return 0;
+        }
+
+        let (time, record) = (self.time as u128, self.distance as u128);
+        let beats = |hold: u128| hold * (time - hold) > record;
+
+        // The shortest winning hold is near the smaller root of
+        // hold * (time - hold) = record + 1; the square root is rounded down.
+        let delta = (time.pow(2) - 4 * (record + 1)).isqrt();
+        let mut lower = (time - delta) / 2;
+        while !beats(lower) {
+            lower += 1;
+        }
+        while lower > 0 && beats(lower - 1) {
+            lower -= 1;
+        }
+
+        // Holding for `time - lower` goes just as far.
+        (time - 2 * lower + 1) as u64
+    }
+}
+
+/// The race of part two, whose numbers are those of every race written
+/// without the spaces between them. They may not fit an integer.
+pub struct LongRace {
+    pub time: f64,
+    pub distance: f64,
+}
+
+impl LongRace {
+    /// Joins the numbers of `races` in order. Numbers beyond 2^53 are
+    /// rejected, since floats no longer hold every integer there.
+    pub fn from_races(races: &[Race]) -> Result<LongRace> {
+        const MAX_EXACT: f64 = 9_007_199_254_740_992.0;
+
+        if races.is_empty() {
+            return Err(Error::new("no races to combine"));
+        }
+
+        let join = |number: fn(&Race) -> u64| -> f64 {
+            let digits = races.iter().map(number).join("");
+            digits.parse().expect("joined numbers are digits")
+        };
+
+        let (time, distance) = (join(|race| race.time), join(|race| race.distance));
+        if time > MAX_EXACT || distance > MAX_EXACT {
+            return Err(Error::new("the joined race is too long to be solved exactly"));
+        }
+
+        Ok(LongRace { time, distance })
+    }
+
+    /// How many ways of holding the button beat the record distance.
+    pub fn get_victory_range_size(&self) -> f64 {
+        let time: f64 = self.time;
+        let distance: f64 = self.distance + 1.;
+
+        let delta_squared: f64 = time.powf(2.) - 4. * (distance);
+        if delta_squared < 0. {
+            return 0.;
+        }
+        let delta = delta_squared.sqrt();
+
+
+        let upper = ((time + delta) / 2.).floor() as i64;
+        let lower = ((time - delta) / 2.).ceil() as i64;
+
+        (upper - lower + 1) as f64
+    }
+}
+
+/// [`Race::get_victory_range_size`] of every race.
+pub fn get_victory_range_sizes(races: &[Race]) -> Vec<u64> {
+    races.iter().map(|race| race.get_victory_range_size()).collect_vec()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Race>;
+    type Answer = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Race>> {
+        let lines = lines(input).collect_vec();
+
+        let times_line = lines.first().ok_or_else(|| Error::new("missing times line"))?;
+        let distances_line = lines.get(1).ok_or_else(|| Error::new("missing distances line"))?;
+
+        let (_, times) = parse::labeled_numbers::<u64>(*times_line)?;
+        let (_, distances) = parse::labeled_numbers::<u64>(*distances_line)?;
+
+        if times.len() != distances.len() {
+            let message = format!("expected {} distances, found {}", times.len(), distances.len());
+            return Err(distances_line.error(message));
+        }
+
+        Ok(zip(times, distances).map(|(time, distance)| Race { time, distance }).collect_vec())
+    }
+
+    fn part_one(&self, races: &Vec<Race>) -> Result<u64> {
+        let ranges = get_victory_range_sizes(races);
+
+        log::debug!("ranges: {:?}", &ranges);
+
+        if ranges.is_empty() {
+            return Err(Error::new("no races to combine"));
+        }
+
+        ranges
+            .into_iter()
+            .try_fold(1_u64, u64::checked_mul)
+            .ok_or_else(|| Error::new("the product of the ranges does not fit in 64 bits"))
+    }
+
+    fn part_two(&self, races: &Vec<Race>) -> Result<u64> {
+        Ok(LongRace::from_races(races)?.get_victory_range_size() as u64)
+    }
+
+    fn diagnostics(&self, races: &Vec<Race>, part: Part) -> Result<Vec<Diagnostic>> {
+        if part == Part::Two {
+            let race = LongRace::from_races(races)?;
+            let race = vec![race.time as u64, race.distance as u64];
+            return Ok(vec![Diagnostic::new("long_race", race)]);
+        }
+
+        Ok(vec![Diagnostic::new("ranges", get_victory_range_sizes(races))])
+    }
+}
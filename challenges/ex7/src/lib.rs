@@ -0,0 +1,221 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use utils::{lines, log, Diagnostic, Part, Result, Solver, Span};
+
+/// What a `J` stands for: a jack in part one, a joker in part two.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Rules {
+    Jacks,
+    Jokers,
+}
+
+/// The strength of a card, for breaking ties between hands of one type.
+pub fn map_char_to_value(ch: char, rules: Rules) -> u32 {
+    match ch {
+        'A' => 14,
+        'K' => 13,
+        'Q' => 12,
+        'J' if rules == Rules::Jacks => 11,
+        'J' => 1,
+        'T' => 10,
+        _ => ch.to_string().parse().unwrap(),
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum HandType {
+    Five = 6,
+    Four = 5,
+    FullHouse = 4,
+    Three = 3,
+    TwoPair = 2,
+    Pair = 1,
+    High = 0,
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub struct Hand {
+    pub cards: String,
+}
+
+impl Hand {
+    /// How many times each card appears in the hand.
+    pub fn get_cards_count(&self) -> HashMap<char, u8> {
+        let mut counts: HashMap<char, u8> = HashMap::new();
+
+        for char in self.cards.chars() {
+            if let Some(count) = counts.get_mut(&char) {
+                *count += 1;
+            } else {
+                counts.insert(char, 1);
+            }
+        }
+
+        counts
+    }
+
+    /// The type of the hand, which decides its rank first. Under
+    /// [`Rules::Jokers`], jokers count as whichever card makes the best hand.
+    pub fn get_hand_type(&self, rules: Rules) -> HandType {
+        let mut counts = self.get_cards_count();
+
+        // convert Js into the most favorable card
+        if rules == Rules::Jokers {
+            let j_count = *counts.get(&'J').unwrap_or(&0);
+            if let Some(max_count_card) = counts
+                .iter()
+                .filter(|pair| *pair.0 != 'J')
+                .max_by_key(|pair| pair.1)
+                .map(|(&ch, _)| ch)
+            {
+                match j_count {
+                    5 | 0 => (),
+                    _ => {
+                        if let Some(max_count) = counts.get_mut(&max_count_card) {
+                            *max_count += j_count;
+                        }
+                        counts.remove(&'J');
+                    }
+                }
+            }
+        }
+
+        let unique_cards = counts.keys().len();
+        let max_repetitions = *counts.values().max().unwrap();
+
+        let hand_type = match unique_cards {
+            5 => HandType::High,
+            4 => HandType::Pair,
+            3 => match max_repetitions {
+                2 => HandType::TwoPair,
+                3 => HandType::Three,
+                _ => panic!("Should not happen"),
+            },
+            2 => match max_repetitions {
+                4 => HandType::Four,
+                3 => HandType::FullHouse,
+                _ => panic!("Should not happen"),
+            },
+            1 => HandType::Five,
+            _ => panic!("Should not happen"),
+        };
+        hand_type
+    }
+
+    /// The strength of every card, in order.
+    pub fn get_hand_values(&self, rules: Rules) -> Vec<u32> {
+        self.cards
+            .chars()
+            .map(|ch| map_char_to_value(ch, rules))
+            .collect_vec()
+    }
+
+    /// Orders hands by type, then by the strength of their cards in order.
+    pub fn compare(&self, other: &Hand, rules: Rules) -> std::cmp::Ordering {
+        let my_hand_type = self.get_hand_type(rules) as u32;
+        let other_hand_type = other.get_hand_type(rules) as u32;
+
+        if my_hand_type > other_hand_type {
+            std::cmp::Ordering::Greater
+        } else if my_hand_type < other_hand_type {
+            std::cmp::Ordering::Less
+        } else {
+            let my_hand_values = self.get_hand_values(rules);
+            let other_hand_values = other.get_hand_values(rules);
+
+            for (my_value, other_value) in std::iter::zip(my_hand_values, other_hand_values) {
+                if my_value > other_value {
+                    return std::cmp::Ordering::Greater;
+                } else if my_value < other_value {
+                    return std::cmp::Ordering::Less;
+                }
+            }
+            std::cmp::Ordering::Equal
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Bid {
+    pub hand: Hand,
+    pub bid: u32,
+}
+
+const CARDS: &str = "AKQJT98765432";
+
+/// Parses a `<cards> <bid>` line.
+pub fn parse_line(line: Span) -> Result<Bid> {
+    let mut parts = line.split_whitespace();
+
+    let cards = parts.next().ok_or_else(|| line.error("missing hand"))?;
+    let bid = parts.next().ok_or_else(|| line.error("missing bid"))?;
+
+    if let Some((card, ch)) = cards.chars().find(|(_, ch)| !CARDS.contains(*ch)) {
+        return Err(card.error(format!("unknown card '{}'", ch)));
+    }
+    if cards.as_str().len() != 5 {
+        return Err(cards.error(format!("expected 5 cards, found '{}'", cards)));
+    }
+
+    let hand = Hand {
+        cards: cards.to_string(),
+    };
+
+    let bid = bid.parse::<u32>()?;
+
+    Ok(Bid { hand, bid })
+}
+
+/// Every bid multiplied by the rank of its hand, the weakest ranking 1.
+pub fn total_winnings(bids: &[Bid], rules: Rules) -> u64 {
+    let mut bids = bids.iter().collect_vec();
+
+    bids.sort_by(|this, other| this.hand.compare(&other.hand, rules));
+
+    log::trace!("bids: {:?}", bids);
+
+    let mapped_bids = bids
+        .iter()
+        .enumerate()
+        .map(|(rank, bid)| bid.bid as u64 * (rank as u64 + 1))
+        .collect_vec();
+
+    log::trace!("mapped bids: {:?}", mapped_bids);
+
+    mapped_bids.into_iter().sum()
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Bid>;
+    type Answer = u64;
+
+    fn parse(&self, input: &str) -> Result<Vec<Bid>> {
+        lines(input).map(parse_line).collect()
+    }
+
+    fn part_one(&self, bids: &Vec<Bid>) -> Result<u64> {
+        Ok(total_winnings(bids, Rules::Jacks))
+    }
+
+    fn part_two(&self, bids: &Vec<Bid>) -> Result<u64> {
+        Ok(total_winnings(bids, Rules::Jokers))
+    }
+
+    fn diagnostics(&self, bids: &Vec<Bid>, part: Part) -> Result<Vec<Diagnostic>> {
+        let rules = match part {
+            Part::One => Rules::Jacks,
+            Part::Two => Rules::Jokers,
+        };
+        let hand_types = bids
+            .iter()
+            .map(|bid| format!("{:?}", bid.hand.get_hand_type(rules)))
+            .join(" ");
+
+        Ok(vec![Diagnostic::new("hand_types", hand_types)])
+    }
+}
@@ -0,0 +1,166 @@
+use std::collections::{HashMap, HashSet};
+
+use num::integer::gcd;
+
+use itertools::Itertools;
+use utils::{log, parse, sections, Diagnostic, Error, Part, Result, Solver};
+
+/// A node of the network and the nodes its left and right turns lead to.
+pub struct Node {
+    pub id: String,
+    pub left: String,
+    pub right: String,
+}
+
+pub struct Map {
+    pub directions: Vec<char>,
+    pub nodes: HashMap<String, Node>,
+}
+
+impl Map {
+    /// Steps from `AAA` to `ZZZ`, following the directions.
+    pub fn count_steps(&self) -> Result<u64> {
+        let start = self
+            .nodes
+            .get("AAA")
+            .ok_or_else(|| Error::new("missing start node AAA"))?;
+        if !self.nodes.contains_key("ZZZ") {
+            return Err(Error::new("missing goal node ZZZ"));
+        }
+
+        self.walk(start, |node| node.id == "ZZZ")
+    }
+
+    /// Steps from `start` to the first node that `is_goal`, following the
+    /// directions. Coming back to a node at the same point of the directions
+    /// means the walk loops forever without reaching a goal.
+    fn walk(&self, start: &Node, is_goal: impl Fn(&Node) -> bool) -> Result<u64> {
+        let mut seen = HashSet::new();
+        let mut curr_node = start;
+        let mut steps: usize = 0;
+
+        while !is_goal(curr_node) {
+            let turn = steps % self.directions.len();
+            if !seen.insert((&curr_node.id, turn)) {
+                let message = format!("no goal can be reached from {}", start.id);
+                return Err(Error::new(message));
+            }
+
+            let next_node_id = match self.directions[turn] {
+                'L' => &curr_node.left,
+                'R' => &curr_node.right,
+                _ => panic!("Should not happen"),
+            };
+
+            curr_node = &self.nodes[next_node_id];
+            steps += 1;
+        }
+
+        Ok(steps as u64)
+    }
+
+    /// The nodes ending with A, by id.
+    pub fn get_starting_nodes(&self) -> Vec<&Node> {
+        self.nodes
+            .iter()
+            .filter_map(|(node_id, node)| {
+                if node_id.ends_with("A") {
+                    Some(node)
+                } else {
+                    None
+                }
+            })
+            .sorted_by_key(|node| &node.id)
+            .collect_vec()
+    }
+
+    /// Steps from every starting node to its first node ending with Z, in
+    /// starting node order.
+    pub fn get_cycle_sizes(&self) -> Result<Vec<u64>> {
+        self.get_starting_nodes()
+            .into_iter()
+            .map(|start_node| self.walk(start_node, |node| node.id.ends_with("Z")))
+            .collect()
+    }
+
+    /// Steps until every starting node is on a node ending with Z at once,
+    /// assuming each of them loops back there after as many steps.
+    pub fn count_ghost_steps(&self) -> Result<u64> {
+        let cycle_sizes = self.get_cycle_sizes()?;
+
+        log::debug!("cycle sizes: {:?}", cycle_sizes);
+
+        if cycle_sizes.is_empty() {
+            return Err(Error::new("no start nodes ending with A"));
+        }
+
+        cycle_sizes
+            .into_iter()
+            .try_fold(1_u64, |steps, size| steps.checked_mul(size / gcd(steps, size)))
+            .ok_or_else(|| Error::new("the number of steps does not fit in 64 bits"))
+    }
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 8;
+
+    type Input = Map;
+    type Answer = u64;
+
+    fn parse(&self, input: &str) -> Result<Map> {
+        let sections = sections(input);
+
+        let directions_line = sections.get(0)?.line(0)?;
+        if let Some((direction, ch)) = directions_line.chars().find(|(_, ch)| *ch != 'L' && *ch != 'R') {
+            return Err(direction.error(format!("unknown direction '{}'", ch)));
+        }
+
+        let directions = directions_line.as_str().chars().collect_vec();
+
+        let node_spans = sections
+            .get(1)?
+            .lines()
+            .iter()
+            .map(|line| parse::key_pair(*line))
+            .collect::<Result<Vec<_>>>()?;
+
+        let nodes: HashMap<String, Node> = node_spans
+            .iter()
+            .map(|(key, left_node, right_node)| {
+                let node = Node {
+                    id: key.to_string(),
+                    left: left_node.to_string(),
+                    right: right_node.to_string(),
+                };
+
+                (key.to_string(), node)
+            })
+            .collect();
+
+        for child in node_spans.iter().flat_map(|(_, left, right)| [left, right]) {
+            if !nodes.contains_key(child.as_str()) {
+                return Err(child.error(format!("unknown node '{}'", child)));
+            }
+        }
+
+        Ok(Map { directions, nodes })
+    }
+
+    fn part_one(&self, map: &Map) -> Result<u64> {
+        map.count_steps()
+    }
+
+    fn part_two(&self, map: &Map) -> Result<u64> {
+        map.count_ghost_steps()
+    }
+
+    fn diagnostics(&self, map: &Map, part: Part) -> Result<Vec<Diagnostic>> {
+        if part == Part::One {
+            return Ok(Vec::new());
+        }
+
+        Ok(vec![Diagnostic::new("cycle_sizes", map.get_cycle_sizes()?)])
+    }
+}
@@ -0,0 +1,99 @@
+use utils::{lines, log, parse, Diagnostic, Error, Part, Result, Solver};
+
+/// The differences between consecutive values of `seq`, or `None` if one
+/// does not fit in an `i32`.
+pub fn get_differences(seq: &[i32]) -> Option<Vec<i32>> {
+    seq.windows(2).map(|pair| pair[1].checked_sub(pair[0])).collect()
+}
+
+/// The value following `seq`, extrapolated from its differences, or `None`
+/// if a value on the way does not fit in an `i32`.
+pub fn get_next_value(seq: &[i32]) -> Option<i32> {
+    if seq.iter().all(|&v| v == 0) {
+        Some(0)
+    } else {
+        let last = seq.last().unwrap();
+        let differences = get_differences(seq)?;
+        let next_value = get_next_value(&differences)?;
+        last.checked_add(next_value)
+    }
+}
+
+/// [`get_next_value`] of every sequence.
+pub fn get_next_values(sequences: &[Vec<i32>]) -> Result<Vec<i32>> {
+    extrapolate(sequences, get_next_value)
+}
+
+/// The value preceding `seq`, extrapolated from its differences, or `None`
+/// if a value on the way does not fit in an `i32`.
+pub fn get_prev_value(seq: &[i32]) -> Option<i32> {
+    if seq.iter().all(|&v| v == 0) {
+        Some(0)
+    } else {
+        let first = seq.first().unwrap();
+        let differences = get_differences(seq)?;
+        let prev_value = get_prev_value(&differences)?;
+        first.checked_sub(prev_value)
+    }
+}
+
+/// [`get_prev_value`] of every sequence.
+pub fn get_prev_values(sequences: &[Vec<i32>]) -> Result<Vec<i32>> {
+    extrapolate(sequences, get_prev_value)
+}
+
+fn extrapolate(sequences: &[Vec<i32>], value: fn(&[i32]) -> Option<i32>) -> Result<Vec<i32>> {
+    sequences
+        .iter()
+        .enumerate()
+        .map(|(idx, seq)| {
+            value(seq).ok_or_else(|| {
+                Error::new(format!("sequence {}: values do not fit in 32 bits", idx + 1))
+            })
+        })
+        .collect()
+}
+
+/// The sum of `values`, unless it does not fit in an `i32`.
+fn sum(values: &[i32]) -> Result<i32> {
+    values
+        .iter()
+        .try_fold(0_i32, |total, &value| total.checked_add(value))
+        .ok_or_else(|| Error::new("the sum does not fit in 32 bits"))
+}
+
+pub struct Solution;
+
+impl Solver for Solution {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Vec<i32>>;
+    type Answer = i32;
+
+    fn parse(&self, input: &str) -> Result<Vec<Vec<i32>>> {
+        lines(input).map(parse::numbers::<i32>).collect()
+    }
+
+    fn part_one(&self, sequences: &Vec<Vec<i32>>) -> Result<i32> {
+        let next_values = get_next_values(sequences)?;
+
+        log::debug!("values: {:?}", next_values);
+
+        sum(&next_values)
+    }
+
+    fn part_two(&self, sequences: &Vec<Vec<i32>>) -> Result<i32> {
+        let prev_values = get_prev_values(sequences)?;
+
+        log::debug!("values: {:?}", prev_values);
+
+        sum(&prev_values)
+    }
+
+    fn diagnostics(&self, sequences: &Vec<Vec<i32>>, part: Part) -> Result<Vec<Diagnostic>> {
+        Ok(match part {
+            Part::One => vec![Diagnostic::new("next_values", get_next_values(sequences)?)],
+            Part::Two => vec![Diagnostic::new("prev_values", get_prev_values(sequences)?)],
+        })
+    }
+}
@@ -0,0 +1,10 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use utils::{Input, Solver};
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = Input::from_bytes("fuzz", data.to_vec()) {
+        let _ = ex3::Solution.parse(&input);
+    }
+});
@@ -0,0 +1,80 @@
+//! Hashes the sources of every day, so that cached answers are dropped as
+//! soon as the code that found them changes. A day's version covers its own
+//! crate and `utils`, whose parsers and helpers every day builds on.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn main() -> io::Result<()> {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap());
+    let challenges = manifest_dir.join("../challenges");
+    let utils = manifest_dir.join("../utils");
+
+    println!("cargo:rerun-if-changed={}", challenges.display());
+    println!("cargo:rerun-if-changed={}", utils.join("Cargo.toml").display());
+    println!("cargo:rerun-if-changed={}", utils.join("src").display());
+
+    let mut shared = Vec::new();
+    collect(&utils, &utils.join("Cargo.toml"), &mut shared)?;
+    collect(&utils, &utils.join("src"), &mut shared)?;
+
+    let mut versions = Vec::new();
+    for entry in fs::read_dir(&challenges)? {
+        let path = entry?.path();
+        let Some(day) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_prefix("ex"))
+            .and_then(|day| day.parse::<u8>().ok())
+        else {
+            continue;
+        };
+
+        let mut files = shared.clone();
+        collect(&path, &path.join("Cargo.toml"), &mut files)?;
+        collect(&path, &path.join("src"), &mut files)?;
+
+        let hash = files.iter().fold(FNV_OFFSET, |hash, (name, contents)| {
+            let hash = fnv1a(hash, name.as_bytes());
+            fnv1a(hash, contents)
+        });
+        versions.push((day, hash));
+    }
+    versions.sort();
+
+    let entries: String = versions
+        .iter()
+        .map(|(day, hash)| format!("    ({}, \"{:016x}\"),\n", day, hash))
+        .collect();
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("source_versions.rs");
+    fs::write(out, format!("const SOURCE_VERSIONS: &[(u8, &str)] = &[\n{}];\n", entries))
+}
+
+/// Adds the file at `path`, or every file below it, with their contents.
+/// Names are relative to `root`, so moving the checkout changes nothing.
+fn collect(root: &Path, path: &Path, files: &mut Vec<(String, Vec<u8>)>) -> io::Result<()> {
+    if path.is_dir() {
+        let mut entries = fs::read_dir(path)?
+            .map(|entry| entry.map(|entry| entry.path()))
+            .collect::<io::Result<Vec<_>>>()?;
+        entries.sort();
+        for entry in entries {
+            collect(root, &entry, files)?;
+        }
+    } else if path.is_file() {
+        let name = path.strip_prefix(root).unwrap_or(path);
+        files.push((name.display().to_string(), fs::read(path)?));
+    }
+    Ok(())
+}
+
+const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+
+/// 64-bit FNV-1a, the same hash the answer cache uses for inputs.
+fn fnv1a(hash: u64, bytes: &[u8]) -> u64 {
+    bytes
+        .iter()
+        .fold(hash, |hash, &byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
@@ -0,0 +1,235 @@
+use std::fmt::{self, Display};
+use std::panic::{self, AssertUnwindSafe};
+use std::time::{Duration, Instant};
+
+use clap::Args;
+use rayon::prelude::*;
+use rayon::ThreadPoolBuilder;
+use utils::{input_name, Cache, Diagnostic, Input, Error, Key, Part, Registry, Result};
+
+use crate::output::{print_records, Format, Record};
+use crate::resolve_input;
+
+#[derive(Args)]
+pub struct AllArgs {
+    /// Input name under inputs/<day>/ (example, example2, input)
+    #[arg(short, long, default_value = "input")]
+    input: String,
+
+    /// Number of worker threads, defaults to one per CPU
+    #[arg(short, long)]
+    jobs: Option<usize>,
+
+    /// Output format; json and csv include the solvers' diagnostics
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+
+    /// Solve everything again instead of reusing the cached answers
+    #[arg(long)]
+    no_cache: bool,
+}
+
+enum Status {
+    Ok,
+    Error,
+    Panic,
+}
+
+/// The outcome of one day and part.
+struct Row {
+    day: u8,
+    part: Part,
+    answer: String,
+    diagnostics: Vec<Diagnostic>,
+    time: Duration,
+    status: Status,
+    /// Whether the answer came from the cache, so `time` says nothing.
+    cached: bool,
+    /// Where to cache a newly found answer.
+    key: Option<Key>,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Ok => "OK",
+            Status::Error => "ERROR",
+            Status::Panic => "PANIC",
+        };
+
+        f.pad(status)
+    }
+}
+
+impl Row {
+    fn into_record(self) -> Record {
+        let (answer, error) = match self.status {
+            Status::Ok => (Some(self.answer), None),
+            Status::Error | Status::Panic => (None, Some(self.answer)),
+        };
+
+        Record {
+            day: self.day,
+            part: self.part.number(),
+            answer,
+            error,
+            status: Some(self.status.to_string()),
+            time_ns: (!self.cached).then_some(self.time.as_nanos() as u64),
+            diagnostics: self.diagnostics,
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+/// Reads the input, then parses and solves it unless `cache` has its answer.
+/// The time covers parsing and solving only, or everything up to the failure.
+fn solve(
+    registry: &Registry,
+    cache: Option<&Cache>,
+    day: u8,
+    part: Part,
+    input: &str,
+    with_diagnostics: bool,
+) -> Row {
+    let input_path = resolve_input(day, input);
+    let solver = registry.get(day, part).expect("entries are registered");
+    let mut time = None;
+    let mut cached = false;
+    let mut key = None;
+    let start = Instant::now();
+
+    let result = panic::catch_unwind(AssertUnwindSafe(|| -> Result<(String, Vec<Diagnostic>)> {
+        let input = Input::open(&input_path)?;
+
+        let mut new_key = None;
+        if let Some(cache) = cache {
+            let input_key = Key::new(day, part, aoc::source_version(day), &input);
+            if let Some(answer) = cache.get(&input_key) {
+                cached = true;
+                return Ok((answer.to_string(), Vec::new()));
+            }
+            new_key = Some(input_key);
+        }
+
+        let start = Instant::now();
+        let parsed = solver.parse(&input)?;
+        let answer = solver.solve(parsed.as_ref(), part)?;
+        time = Some(start.elapsed());
+        key = new_key;
+
+        let diagnostics = match with_diagnostics {
+            true => solver.diagnostics(parsed.as_ref(), part)?,
+            false => Vec::new(),
+        };
+
+        Ok((answer, diagnostics))
+    }));
+    let time = time.unwrap_or_else(|| start.elapsed());
+
+    let (answer, diagnostics, status) = match result {
+        Ok(Ok((answer, diagnostics))) => (answer, diagnostics, Status::Ok),
+        Ok(Err(err)) => {
+            let message = err.in_file(input_name(&input_path)).to_string();
+            (message, Vec::new(), Status::Error)
+        }
+        Err(payload) => (panic_message(payload.as_ref()), Vec::new(), Status::Panic),
+    };
+
+    Row {
+        day,
+        part,
+        answer,
+        diagnostics,
+        time,
+        status,
+        cached,
+        key,
+    }
+}
+
+fn print_table(rows: &[Row]) {
+    println!("{:>3} {:>4} {:>10}  {:<6} Answer", "Day", "Part", "Time", "Status");
+    for row in rows {
+        println!(
+            "{:>3} {:>4} {:>10}  {:<6} {}",
+            row.day,
+            row.part.number(),
+            match row.cached {
+                true => "cached".to_string(),
+                false => format!("{:.2?}", row.time),
+            },
+            row.status,
+            row.answer
+        );
+    }
+}
+
+/// Solves every registered day and part concurrently and prints one table row
+/// each, in calendar order. Panics are caught and reported like errors; the
+/// default panic output is silenced meanwhile so it does not interleave with
+/// the table. Answers found in the cache are not solved again, unless the
+/// diagnostics behind them are asked for.
+pub fn all(args: AllArgs) -> Result<()> {
+    let registry = aoc::registry();
+    let entries = registry.entries();
+    let with_diagnostics = args.format != Format::Text;
+    let mut cache = match args.no_cache || with_diagnostics {
+        true => None,
+        false => Some(Cache::load(Cache::default_path())?),
+    };
+
+    let mut pool = ThreadPoolBuilder::new();
+    if let Some(jobs) = args.jobs {
+        pool = pool.num_threads(jobs);
+    }
+    let pool = pool.build().map_err(|err| Error::new(err.to_string()))?;
+
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| ()));
+    let rows: Vec<Row> = pool.install(|| {
+        entries
+            .par_iter()
+            .map(|&(day, part)| {
+                solve(&registry, cache.as_ref(), day, part, &args.input, with_diagnostics)
+            })
+            .collect()
+    });
+    panic::set_hook(hook);
+
+    if let Some(cache) = &mut cache {
+        for row in &rows {
+            if let Some(key) = &row.key {
+                cache.insert(key.clone(), row.answer.clone());
+            }
+        }
+        cache.save()?;
+    }
+
+    let failed = rows
+        .iter()
+        .filter(|row| !matches!(row.status, Status::Ok))
+        .count();
+
+    if args.format == Format::Text {
+        print_table(&rows);
+        println!("{} of {} solved", rows.len() - failed, rows.len());
+    } else {
+        let records = rows.into_iter().map(Row::into_record).collect::<Vec<_>>();
+        print_records(args.format, &records);
+    }
+
+    if failed > 0 {
+        return Err(Error::new(format!("{} day/part(s) did not solve", failed)));
+    }
+
+    Ok(())
+}
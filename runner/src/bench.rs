@@ -0,0 +1,231 @@
+use std::fs;
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+
+use clap::Args;
+use serde::Serialize;
+use utils::{log, Error, Input, Part, Result};
+
+use crate::resolve_input;
+
+#[derive(Args)]
+pub struct BenchArgs {
+    /// Only benchmark this day
+    day: Option<u8>,
+
+    /// Only benchmark this part
+    #[arg(short, long)]
+    part: Option<Part>,
+
+    /// Number of timed runs of every phase
+    #[arg(
+        short = 'n',
+        long,
+        default_value_t = 10,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    iterations: u32,
+
+    /// Input name under inputs/<day>/ (example, example2, input)
+    #[arg(short, long, default_value = "input")]
+    input: String,
+
+    /// Also write the results as JSON to this file
+    #[arg(long)]
+    json: Option<PathBuf>,
+}
+
+/// Allocations, allocated bytes and peak bytes of one run of a phase.
+type Stats = (u64, u64, u64);
+
+/// Runs `f`, with what it allocates if the counting allocator is installed,
+/// see the `alloc-stats` feature.
+#[cfg(feature = "alloc-stats")]
+fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Stats>) {
+    let (value, stats) = utils::alloc::measure(f);
+    (value, Some((stats.allocations, stats.allocated_bytes, stats.peak_bytes)))
+}
+
+#[cfg(not(feature = "alloc-stats"))]
+fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Stats>) {
+    (f(), None)
+}
+
+/// Timings of one phase over all iterations, in nanoseconds, and what one
+/// run of it allocates if allocations are counted.
+#[derive(Serialize)]
+struct Timing {
+    day: u8,
+    part: u8,
+    phase: &'static str,
+    min_ns: u64,
+    median_ns: u64,
+    max_ns: u64,
+    allocations: Option<u64>,
+    allocated_bytes: Option<u64>,
+    peak_bytes: Option<u64>,
+}
+
+#[derive(Serialize)]
+struct Report {
+    input: String,
+    iterations: u32,
+    timings: Vec<Timing>,
+    /// Sum of the medians of every phase.
+    total_median_ns: u64,
+}
+
+impl Timing {
+    fn new(
+        day: u8,
+        part: Part,
+        phase: &'static str,
+        mut samples: Vec<Duration>,
+        stats: Option<Stats>,
+    ) -> Timing {
+        samples.sort();
+
+        let mid = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[mid - 1] + samples[mid]) / 2
+        } else {
+            samples[mid]
+        };
+
+        Timing {
+            day,
+            part: part.number(),
+            phase,
+            min_ns: samples[0].as_nanos() as u64,
+            median_ns: median.as_nanos() as u64,
+            max_ns: samples[samples.len() - 1].as_nanos() as u64,
+            allocations: stats.map(|(allocations, _, _)| allocations),
+            allocated_bytes: stats.map(|(_, allocated_bytes, _)| allocated_bytes),
+            peak_bytes: stats.map(|(_, _, peak_bytes)| peak_bytes),
+        }
+    }
+}
+
+fn format_ns(ns: u64) -> String {
+    format!("{:.2?}", Duration::from_nanos(ns))
+}
+
+fn format_count(count: Option<u64>) -> String {
+    count.map_or_else(|| "n/a".to_string(), |count| count.to_string())
+}
+
+fn format_bytes(bytes: Option<u64>) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let Some(bytes) = bytes else {
+        return "n/a".to_string();
+    };
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{}B", bytes),
+        _ => format!("{:.2}{}", value, UNITS[unit]),
+    }
+}
+
+/// Times parsing and solving of every selected part, each in its own
+/// iterations, and counts what the first iteration of each allocates when
+/// built with the `alloc-stats` feature.
+/// Parsing is timed again for every part, as a run of that part alone would
+/// parse, so the two phases of a part add up to what solving it costs.
+pub fn bench(args: BenchArgs) -> Result<()> {
+    let registry = aoc::registry();
+
+    let entries: Vec<(u8, Part)> = registry
+        .entries()
+        .into_iter()
+        .filter(|&(day, part)| {
+            args.day.is_none_or(|wanted| day == wanted)
+                && args.part.is_none_or(|wanted| part == wanted)
+        })
+        .collect();
+
+    if entries.is_empty() {
+        return Err(Error::new("no registered solution matches the selection"));
+    }
+
+    let mut timings = Vec::new();
+
+    for (day, part) in entries {
+        let input_path = resolve_input(day, &args.input);
+        if args.day.is_none() && !input_path.exists() {
+            log::warn!("skipping day {} part {}: no {}", day, part, input_path.display());
+            continue;
+        }
+
+        let input = Input::open(&input_path)?;
+        let solver = registry.get(day, part).expect("entries are registered");
+
+        let mut parse_samples = Vec::new();
+        let mut solve_samples = Vec::new();
+        let mut parse_stats = None;
+        let mut solve_stats = None;
+
+        for _ in 0..args.iterations {
+            let start = Instant::now();
+            let (parsed, stats) = measure(|| solver.parse(&input));
+            let parsed = parsed.map_err(|err| err.in_file(input.path()))?;
+            parse_samples.push(start.elapsed());
+            parse_stats.get_or_insert(stats);
+
+            let start = Instant::now();
+            let (answer, stats) = measure(|| solver.solve(parsed.as_ref(), part));
+            answer.map_err(|err| err.in_file(input.path()))?;
+            solve_samples.push(start.elapsed());
+            solve_stats.get_or_insert(stats);
+        }
+
+        let stats = |stats: Option<_>| stats.expect("there is at least one iteration");
+        timings.push(Timing::new(day, part, "parse", parse_samples, stats(parse_stats)));
+        timings.push(Timing::new(day, part, "solve", solve_samples, stats(solve_stats)));
+    }
+
+    let report = Report {
+        input: args.input,
+        iterations: args.iterations,
+        total_median_ns: timings.iter().map(|timing| timing.median_ns).sum(),
+        timings,
+    };
+
+    println!(
+        "{:>3} {:>4} {:<5} {:>10} {:>10} {:>10} {:>9} {:>10} {:>10}",
+        "Day", "Part", "Phase", "Min", "Median", "Max", "Allocs", "Allocated", "Peak"
+    );
+    for timing in &report.timings {
+        println!(
+            "{:>3} {:>4} {:<5} {:>10} {:>10} {:>10} {:>9} {:>10} {:>10}",
+            timing.day,
+            timing.part,
+            timing.phase,
+            format_ns(timing.min_ns),
+            format_ns(timing.median_ns),
+            format_ns(timing.max_ns),
+            format_count(timing.allocations),
+            format_bytes(timing.allocated_bytes),
+            format_bytes(timing.peak_bytes)
+        );
+    }
+    println!(
+        "Total of medians: {} over {} iteration(s)",
+        format_ns(report.total_median_ns),
+        report.iterations
+    );
+
+    if let Some(path) = &args.json {
+        let json = serde_json::to_string_pretty(&report).expect("report is serializable");
+        fs::write(path, json + "\n").map_err(|err| Error::new(err.to_string()).in_file(path))?;
+    }
+
+    Ok(())
+}
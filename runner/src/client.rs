@@ -0,0 +1,135 @@
+//! Talks to adventofcode.com, or whatever server `base_url` points at.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use ureq::http::Response;
+use ureq::{Agent, Body};
+use utils::config::{Config, SESSION_VAR};
+use utils::{Error, Part, Result};
+
+pub const YEAR: u16 = 2023;
+
+const USER_AGENT: &str = concat!("aoc_2023 runner/", env!("CARGO_PKG_VERSION"));
+
+/// An authenticated client for one server.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: Agent,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Client {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            .user_agent(USER_AGENT)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+
+        Client {
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            session: session.into(),
+            agent,
+        }
+    }
+
+    /// A client for the server and session token of the environment and
+    /// config file, see [`Config::session`] and [`Config::base_url`].
+    pub fn from_config() -> Result<Client> {
+        let config = Config::load()?;
+        let session = config.session().ok_or_else(|| {
+            Error::new(format!(
+                "no session token, set {} or `session` in the config file",
+                SESSION_VAR
+            ))
+        })?;
+
+        Ok(Client::new(config.base_url(), session))
+    }
+
+    fn url(&self, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, YEAR, day, path)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    /// Fetches the puzzle input of `day`.
+    pub fn fetch_input(&self, day: u8) -> Result<String> {
+        let url = self.url(day, "/input");
+
+        let response = self
+            .agent
+            .get(&url)
+            .header("Cookie", &self.cookie())
+            .call();
+
+        read_body(&url, response)
+    }
+
+    /// Posts `answer` for `part` of `day` and returns the page the server
+    /// answers with.
+    pub fn post_answer(&self, day: u8, part: Part, answer: &str) -> Result<String> {
+        let url = self.url(day, "/answer");
+
+        let response = self
+            .agent
+            .post(&url)
+            .header("Cookie", &self.cookie())
+            .send_form([("level", part.to_string()), ("answer", answer.to_string())]);
+
+        read_body(&url, response)
+    }
+
+    /// Makes sure the input of `day` is in `<root>/<day>/input.txt`, and
+    /// returns that path along with whether it had to be downloaded. An input
+    /// that is already there is never downloaded again.
+    pub fn download_input(&self, day: u8, root: &Path) -> Result<(PathBuf, bool)> {
+        let path = root.join(day.to_string()).join("input.txt");
+        if path.exists() {
+            return Ok((path, false));
+        }
+
+        let input = self.fetch_input(day)?;
+        write_atomically(&path, &input)?;
+
+        Ok((path, true))
+    }
+}
+
+/// The body of a successful response; anything else is an error naming `url`
+/// and the first line of the body, which is where the server explains itself.
+fn read_body(
+    url: &str,
+    response: std::result::Result<Response<Body>, ureq::Error>,
+) -> Result<String> {
+    let http_error = |err: ureq::Error| Error::new(format!("{}: {}", url, err));
+
+    let mut response = response.map_err(http_error)?;
+    let status = response.status();
+    let body = response.body_mut().read_to_string().map_err(http_error)?;
+
+    if !status.is_success() {
+        let reason = body.lines().next().unwrap_or("").trim();
+        return Err(Error::new(format!("{}: HTTP {}: {}", url, status.as_u16(), reason)));
+    }
+
+    Ok(body)
+}
+
+/// Writes through a temporary file, so an interrupted download never leaves
+/// a truncated input that would then count as cached.
+fn write_atomically(path: &Path, contents: &str) -> Result<()> {
+    let io_error = |err: std::io::Error| Error::new(err.to_string()).in_file(path);
+    let partial = path.with_extension("txt.part");
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(&partial, contents).map_err(io_error)?;
+    fs::rename(&partial, path).map_err(io_error)
+}
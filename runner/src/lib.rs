@@ -0,0 +1,34 @@
+pub mod client;
+pub mod submit;
+
+use utils::Registry;
+
+include!(concat!(env!("OUT_DIR"), "/source_versions.rs"));
+
+/// Builds the registry with every solution in the workspace.
+pub fn registry() -> Registry {
+    let mut registry = Registry::new();
+
+    registry
+        .register(ex1::Solution)
+        .register(ex2::Solution)
+        .register(ex3::Solution)
+        .register(ex4::Solution)
+        .register(ex5::Solution)
+        .register(ex6::Solution)
+        .register(ex7::Solution)
+        .register(ex8::Solution)
+        .register(ex9::Solution);
+
+    registry
+}
+
+/// A hash of the sources of `day` and of `utils`, computed when the runner is
+/// built. It identifies the answers of a day in the [`utils::Cache`], so they
+/// are found again only as long as nothing they depend on changed.
+pub fn source_version(day: u8) -> &'static str {
+    SOURCE_VERSIONS
+        .iter()
+        .find(|(other, _)| *other == day)
+        .map_or("", |(_, version)| version)
+}
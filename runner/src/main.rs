@@ -0,0 +1,409 @@
+use std::fmt::{self, Display};
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+mod all;
+mod bench;
+mod output;
+mod scaffold;
+mod watch;
+
+use all::AllArgs;
+use bench::BenchArgs;
+use aoc::client::Client;
+use aoc::submit::{self, History, Outcome};
+use clap::{Args, Parser, Subcommand};
+use output::{print_records, Format, Record};
+use scaffold::NewArgs;
+use watch::WatchArgs;
+use utils::config::input_root;
+use utils::generate;
+use utils::{get_input_path, log, Answers, Cache, Error, Input, Key, Part, Registry, Result, STDIN};
+
+/// Lets `bench` report what every phase allocates.
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: utils::alloc::Counting = utils::alloc::Counting;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2023 solutions runner")]
+struct Cli {
+    /// Log more details on stderr (-v info, -vv debug, -vvv trace); AOC_LOG overrides
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve one day against an input file
+    Run(RunArgs),
+    /// Re-run the inputs listed in inputs/<day>/answers.toml and compare the answers
+    Verify(VerifyArgs),
+    /// Time parsing and solving over several iterations
+    Bench(BenchArgs),
+    /// Solve every registered day and part in parallel and print a summary
+    All(AllArgs),
+    /// Download puzzle inputs that are not in the input root yet
+    Fetch(FetchArgs),
+    /// Submit an answer, unless the submission history already rules it out
+    Submit(SubmitArgs),
+    /// Create the crates, inputs directory and registry entries of a new day
+    New(NewArgs),
+    /// Print a random valid input, e.g. to pipe into `aoc run <day> <part> -`
+    Generate(GenerateArgs),
+    /// Re-run a day on its examples and input whenever its sources or inputs change
+    Watch(WatchArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day of the calendar (1-25)
+    day: u8,
+
+    /// Part to solve (1 or 2)
+    part: Part,
+
+    /// Input name under inputs/<day>/ (example, example2, input), a path, or - for stdin
+    #[arg(default_value = "input")]
+    input: String,
+
+    /// Output format; json and csv include the solver's diagnostics
+    #[arg(short, long, value_enum, default_value_t)]
+    format: Format,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Days to download, defaults to every registered day
+    days: Vec<u8>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day of the calendar (1-25)
+    day: u8,
+
+    /// Part to submit (1 or 2)
+    part: Part,
+
+    /// The answer, defaults to solving inputs/<day>/input.txt
+    answer: Option<String>,
+}
+
+#[derive(Args)]
+struct GenerateArgs {
+    /// Day of the calendar (1-25)
+    day: u8,
+
+    /// The same seed always gives the same input
+    #[arg(short, long, default_value_t = 0)]
+    seed: u64,
+
+    /// Number of lines, games, cards... defaults to about the real input's
+    #[arg(short = 'n', long)]
+    size: Option<usize>,
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    /// Only verify this day
+    day: Option<u8>,
+
+    /// Solve everything again instead of reusing the cached answers
+    #[arg(long)]
+    no_cache: bool,
+}
+
+/// Maps an input name to a file: bare names like `example` resolve to
+/// `inputs/<day>/example.txt`, `-` is stdin and anything else is taken as a
+/// path.
+fn resolve_input(day: u8, input: &str) -> PathBuf {
+    if input == STDIN {
+        return PathBuf::from(STDIN);
+    }
+
+    let is_name = input
+        .chars()
+        .all(|ch| ch.is_ascii_alphanumeric() || ch == '_' || ch == '-');
+
+    if is_name {
+        get_input_path(day, Some(&format!("{}.txt", input)))
+    } else {
+        PathBuf::from(input)
+    }
+}
+
+fn run(args: RunArgs) -> Result<()> {
+    let registry = aoc::registry();
+
+    let solver = registry.get(args.day, args.part).ok_or_else(|| {
+        Error::new(format!(
+            "no solution registered for day {} part {}",
+            args.day, args.part
+        ))
+    })?;
+
+    let input_path = resolve_input(args.day, &args.input);
+    let input = Input::open(&input_path)?;
+
+    let parsed = solver
+        .parse(&input)
+        .map_err(|err| err.in_file(input.path()))?;
+    let answer = solver
+        .solve(parsed.as_ref(), args.part)
+        .map_err(|err| err.in_file(input.path()))?;
+
+    if args.format == Format::Text {
+        println!("Day {} part {}: {}", args.day, args.part, answer);
+        return Ok(());
+    }
+
+    let diagnostics = solver
+        .diagnostics(parsed.as_ref(), args.part)
+        .map_err(|err| err.in_file(input.path()))?;
+
+    let record = Record {
+        day: args.day,
+        part: args.part.number(),
+        answer: Some(answer),
+        error: None,
+        status: None,
+        time_ns: None,
+        diagnostics,
+    };
+    print_records(args.format, &[record]);
+
+    Ok(())
+}
+
+/// Outcome of checking one answer against the manifest.
+enum Status {
+    Pass,
+    Fail,
+    Unknown,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::Unknown => "UNKNOWN",
+        };
+
+        f.pad(status)
+    }
+}
+
+fn check(expected: Option<&str>, found: &Result<String>) -> Status {
+    match (expected, found) {
+        (None, _) => Status::Unknown,
+        (Some(expected), Ok(found)) if expected == found => Status::Pass,
+        (Some(_), _) => Status::Fail,
+    }
+}
+
+/// Solves `part` of `day`, unless `cache` already has the answer for this
+/// input and these sources of the day. Tells whether the answer came from the
+/// cache.
+fn solve_cached(
+    registry: &Registry,
+    cache: Option<&mut Cache>,
+    day: u8,
+    part: Part,
+    input: &Input,
+) -> Result<(String, bool)> {
+    let Some(cache) = cache else {
+        return registry.solve(day, part, input).map(|answer| (answer, false));
+    };
+
+    let key = Key::new(day, part, aoc::source_version(day), input);
+    if let Some(answer) = cache.get(&key) {
+        return Ok((answer.to_string(), true));
+    }
+
+    let answer = registry.solve(day, part, input)?;
+    cache.insert(key, answer.clone());
+    Ok((answer, false))
+}
+
+/// Solves every registered part of the real input, and every part of the
+/// other inputs that has an answer in the manifest. Answers missing from the
+/// manifest are reported as UNKNOWN; only a FAIL makes the command fail.
+/// Answers found in the cache are not solved again.
+fn verify(args: VerifyArgs) -> Result<()> {
+    let registry = aoc::registry();
+    let mut cache = match args.no_cache {
+        true => None,
+        false => Some(Cache::load(Cache::default_path())?),
+    };
+
+    let days = match args.day {
+        Some(day) if !registry.days().contains(&day) => {
+            return Err(Error::new(format!("no solution registered for day {}", day)));
+        }
+        Some(day) => vec![day],
+        None => registry.days(),
+    };
+
+    let mut counts = [0; 3];
+
+    for day in days {
+        let answers = Answers::load(get_input_path(day, Some("answers.toml")))?;
+        let parts: Vec<Part> = registry
+            .entries()
+            .into_iter()
+            .filter(|&(entry_day, _)| entry_day == day)
+            .map(|(_, part)| part)
+            .collect();
+
+        let mut files: Vec<&str> = answers.files().collect();
+        if !files.contains(&"input") && resolve_input(day, "input").exists() {
+            files.push("input");
+        }
+
+        for file in files {
+            let input_path = resolve_input(day, file);
+            let input = Input::open(&input_path);
+
+            for &part in &parts {
+                let expected = answers.get(file, part);
+                if expected.is_none() && file != "input" {
+                    continue;
+                }
+
+                let (found, cached) = match &input {
+                    Ok(input) => match solve_cached(&registry, cache.as_mut(), day, part, input) {
+                        Ok((answer, cached)) => (Ok(answer), cached),
+                        Err(err) => (Err(err.in_file(input.path())), false),
+                    },
+                    Err(err) => (Err(err.clone()), false),
+                };
+                let status = check(expected.as_deref(), &found);
+
+                let mut details = match (&status, &found) {
+                    (_, Err(err)) => format!("error: {}", err),
+                    (Status::Fail, Ok(found)) => {
+                        format!("expected {}, found {}", expected.unwrap_or_default(), found)
+                    }
+                    (_, Ok(found)) => found.clone(),
+                };
+                if cached {
+                    details.push_str(" (cached)");
+                }
+
+                println!("Day {:>2} part {} {:<10} {:<7} {}", day, part, file, status, details);
+                counts[status as usize] += 1;
+            }
+        }
+    }
+
+    if let Some(cache) = &mut cache {
+        cache.save()?;
+    }
+
+    let [passed, failed, unknown] = counts;
+    println!("{} passed, {} failed, {} unknown", passed, failed, unknown);
+
+    if failed > 0 {
+        return Err(Error::new(format!("{} answer(s) do not match answers.toml", failed)));
+    }
+
+    Ok(())
+}
+
+/// Only asks for a session token once an input is actually missing.
+fn fetch(args: FetchArgs) -> Result<()> {
+    let days = match args.days.is_empty() {
+        true => aoc::registry().days(),
+        false => args.days,
+    };
+    let mut client = None;
+
+    for day in days {
+        let path = get_input_path(day, None);
+        if path.exists() {
+            println!("Day {}: already at {}", day, path.display());
+            continue;
+        }
+
+        let client = match &mut client {
+            Some(client) => client,
+            None => client.insert(Client::from_config()?),
+        };
+        let (path, _) = client.download_input(day, input_root())?;
+        println!("Day {}: downloaded to {}", day, path.display());
+    }
+
+    Ok(())
+}
+
+/// Attempts are kept in `submissions.jsonl` under the input root.
+fn submit(args: SubmitArgs) -> Result<()> {
+    let answer = match args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let input = Input::open(get_input_path(args.day, None))?;
+            aoc::registry()
+                .solve(args.day, args.part, &input)
+                .map_err(|err| err.in_file(input.path()))?
+        }
+    };
+
+    let mut history = History::load(input_root().join("submissions.jsonl"))?;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("the clock is after 1970")
+        .as_secs();
+    history.check(args.day, args.part, &answer, now)?;
+
+    let client = Client::from_config()?;
+    let attempt = submit::submit(&client, &mut history, args.day, args.part, &answer, now)?;
+
+    println!("Day {} part {}: {} is {}", args.day, args.part, answer, attempt.outcome);
+    if let Some(retry_after) = attempt.retry_after {
+        println!("Next attempt possible in {}s", retry_after.saturating_sub(now));
+    }
+
+    match attempt.outcome {
+        Outcome::Correct => Ok(()),
+        outcome => Err(Error::new(format!("answer {} was not accepted: {}", answer, outcome))),
+    }
+}
+
+fn generate(args: GenerateArgs) -> Result<()> {
+    let input = generate::generate(args.day, args.size, args.seed)
+        .ok_or_else(|| Error::new(format!("no input generator for day {}", args.day)))?;
+
+    print!("{}", input);
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    log::init(cli.verbose);
+
+    let result = match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => verify(args),
+        Command::Bench(args) => bench::bench(args),
+        Command::All(args) => all::all(args),
+        Command::Fetch(args) => fetch(args),
+        Command::Submit(args) => submit(args),
+        Command::New(args) => scaffold::new(args),
+        Command::Generate(args) => generate(args),
+        Command::Watch(args) => watch::watch(args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
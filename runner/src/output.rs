@@ -0,0 +1,100 @@
+use clap::ValueEnum;
+use serde::ser::{SerializeMap, Serializer};
+use serde::Serialize;
+use utils::Diagnostic;
+
+/// How results are printed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable lines
+    #[default]
+    Text,
+    /// A JSON object per result, in an array when there are several
+    Json,
+    /// `day,part,field,value` rows, one per answer and diagnostic
+    Csv,
+}
+
+/// One solved (or failed) day and part, as written by the structured formats.
+#[derive(Serialize)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub time_ns: Option<u64>,
+    #[serde(serialize_with = "serialize_diagnostics")]
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+/// Writes diagnostics as a `name: value` object, keeping their order.
+fn serialize_diagnostics<S>(diagnostics: &[Diagnostic], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let mut map = serializer.serialize_map(Some(diagnostics.len()))?;
+    for diagnostic in diagnostics {
+        map.serialize_entry(diagnostic.name, &diagnostic.value)?;
+    }
+    map.end()
+}
+
+fn csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+impl Record {
+    fn csv_rows(&self) -> Vec<(&str, String)> {
+        let mut rows = Vec::new();
+
+        if let Some(answer) = &self.answer {
+            rows.push(("answer", answer.clone()));
+        }
+        if let Some(error) = &self.error {
+            rows.push(("error", error.clone()));
+        }
+        if let Some(status) = &self.status {
+            rows.push(("status", status.clone()));
+        }
+        if let Some(time_ns) = self.time_ns {
+            rows.push(("time_ns", time_ns.to_string()));
+        }
+        for diagnostic in &self.diagnostics {
+            rows.push((diagnostic.name, diagnostic.value.to_string()));
+        }
+
+        rows
+    }
+}
+
+/// Prints `records` as JSON, or CSV. A single record is printed as a JSON
+/// object rather than an array of one.
+pub fn print_records(format: Format, records: &[Record]) {
+    match format {
+        Format::Text => unreachable!("text output is printed by each command"),
+        Format::Json => {
+            let json = match records {
+                [record] => serde_json::to_string_pretty(record),
+                records => serde_json::to_string_pretty(records),
+            };
+            println!("{}", json.expect("records are serializable"));
+        }
+        Format::Csv => {
+            println!("day,part,field,value");
+            for record in records {
+                for (field, value) in record.csv_rows() {
+                    println!("{},{},{},{}", record.day, record.part, field, csv_field(&value));
+                }
+            }
+        }
+    }
+}
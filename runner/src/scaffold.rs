@@ -0,0 +1,169 @@
+//! `aoc new`: creates the crate, inputs directory and registry entry of a
+//! new day from the templates in `runner/templates/`.
+
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clap::Args;
+use utils::config::input_root;
+use utils::{Error, Result};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_RS: &str = include_str!("../templates/lib.rs.in");
+const MAIN_RS: &str = include_str!("../templates/main.rs.in");
+const ANSWERS_TOML: &str = include_str!("../templates/answers.toml.in");
+
+#[derive(Args)]
+pub struct NewArgs {
+    /// Day of the calendar (1-25)
+    day: u8,
+}
+
+/// The workspace root: the nearest directory above the working directory
+/// whose `Cargo.toml` declares a `[workspace]`.
+pub fn workspace_root() -> Result<PathBuf> {
+    let cwd = env::current_dir().map_err(|err| Error::new(err.to_string()))?;
+
+    cwd.ancestors()
+        .find(|dir| {
+            fs::read_to_string(dir.join("Cargo.toml"))
+                .is_ok_and(|manifest| manifest.lines().any(|line| line.trim() == "[workspace]"))
+        })
+        .map(Path::to_path_buf)
+        .ok_or_else(|| Error::new("not inside the workspace, no Cargo.toml with [workspace] found"))
+}
+
+fn crate_name(day: u8) -> String {
+    format!("ex{}", day)
+}
+
+/// The day of a crate name like `ex9`.
+pub fn parse_crate_name(name: &str) -> Option<u8> {
+    name.strip_prefix("ex")?.parse().ok()
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{crate}}", &crate_name(day))
+        .replace("{{day}}", &day.to_string())
+}
+
+fn write_new(path: &Path, contents: &str) -> Result<()> {
+    let io_error = |err: std::io::Error| Error::new(err.to_string()).in_file(path);
+
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(io_error)?;
+    }
+    fs::write(path, contents).map_err(io_error)?;
+    println!("Created {}", path.display());
+
+    Ok(())
+}
+
+/// Adds `entries` to the contiguous block of lines naming a day crate, and
+/// writes the block back sorted by day. `name` finds the crate named
+/// by a line, `line` formats an entry given its crate name and whether it
+/// ends the block.
+fn update_block(
+    path: &Path,
+    entries: &[String],
+    name: impl Fn(&str) -> Option<&str>,
+    line: impl Fn(&str, bool) -> String,
+) -> Result<()> {
+    let key = |line: &str| name(line).and_then(parse_crate_name);
+    let io_error = |err: std::io::Error| Error::new(err.to_string()).in_file(path);
+    let contents = fs::read_to_string(path).map_err(io_error)?;
+    let lines: Vec<&str> = contents.lines().collect();
+
+    let block: Vec<usize> = (0..lines.len())
+        .filter(|&idx| key(lines[idx]).is_some())
+        .collect();
+    let (Some(&start), Some(&end)) = (block.first(), block.last()) else {
+        return Err(Error::new("cannot find where solutions are listed").in_file(path));
+    };
+    if end - start + 1 != block.len() {
+        return Err(Error::new("solutions are not listed in one block").in_file(path));
+    }
+
+    let mut names: Vec<String> = lines[start..=end]
+        .iter()
+        .filter_map(|line| name(line))
+        .map(str::to_string)
+        .collect();
+    names.extend(entries.iter().cloned());
+    names.sort_by_key(|name| parse_crate_name(name));
+    names.dedup();
+
+    let count = names.len();
+    let block = names
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| line(name, idx + 1 == count));
+
+    let mut updated: Vec<String> = lines[..start].iter().map(|line| line.to_string()).collect();
+    updated.extend(block);
+    updated.extend(lines[end + 1..].iter().map(|line| line.to_string()));
+
+    fs::write(path, updated.join("\n") + "\n").map_err(io_error)?;
+    println!("Updated {}", path.display());
+
+    Ok(())
+}
+
+/// Depends on the new crate in `runner/Cargo.toml` and registers its
+/// solution in `runner/src/lib.rs`.
+fn register(root: &Path, names: &[String]) -> Result<()> {
+    update_block(
+        &root.join("runner/Cargo.toml"),
+        names,
+        |line| Some(line.split_once(" = ")?.0),
+        |name, _| {
+            format!(
+                r#"{} = {{ version = "0.1.0", path = "../challenges/{}" }}"#,
+                name, name
+            )
+        },
+    )?;
+
+    update_block(
+        &root.join("runner/src/lib.rs"),
+        names,
+        |line| Some(line.trim().strip_prefix(".register(")?.split_once("::")?.0),
+        |name, last| {
+            let end = if last { ";" } else { "" };
+            format!("        .register({}::Solution){}", name, end)
+        },
+    )
+}
+
+pub fn new(args: NewArgs) -> Result<()> {
+    let day = args.day;
+    if !(1..=25).contains(&day) {
+        return Err(Error::new(format!(
+            "day {} is not in the calendar (1-25)",
+            day
+        )));
+    }
+
+    let root = workspace_root()?;
+    let dir = root.join("challenges").join(crate_name(day));
+
+    if dir.exists() {
+        return Err(Error::new(format!("{} already exists", dir.display())));
+    }
+
+    write_new(&dir.join("Cargo.toml"), &render(CARGO_TOML, day))?;
+    write_new(&dir.join("src/lib.rs"), &render(LIB_RS, day))?;
+    write_new(&dir.join("src/main.rs"), &render(MAIN_RS, day))?;
+
+    let inputs = input_root().join(day.to_string());
+    for (name, contents) in [("example.txt", ""), ("answers.toml", ANSWERS_TOML)] {
+        let path = inputs.join(name);
+        if !path.exists() {
+            write_new(&path, contents)?;
+        }
+    }
+
+    register(&root, &[crate_name(day)])
+}
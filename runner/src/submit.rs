@@ -0,0 +1,284 @@
+//! Answer submission, with a local history of every attempt so known-wrong
+//! answers are never sent twice and the server's cooldowns are respected.
+
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use utils::{Error, Part, Result};
+
+use crate::client::Client;
+
+/// How the server judged an answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint.
+    Wrong,
+    /// Submitted too soon after the previous attempt; nothing was judged.
+    Throttled,
+    /// The part was already solved, or is not unlocked yet.
+    WrongLevel,
+    /// A page the parser does not recognize.
+    Unknown,
+}
+
+/// The server's response to a submission.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Verdict {
+    pub outcome: Outcome,
+    /// Seconds before the next attempt is accepted, when the server says so.
+    pub wait: Option<u64>,
+}
+
+/// One submission, as stored in the history file.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+    /// Unix time of the submission.
+    pub at: u64,
+    /// Unix time before which the server will not take another answer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry_after: Option<u64>,
+}
+
+/// Every past attempt, stored one JSON object per line.
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let outcome = match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Wrong => "wrong",
+            Outcome::Throttled => "submitted too recently",
+            Outcome::WrongLevel => "not the current level, already solved?",
+            Outcome::Unknown => "unrecognized response",
+        };
+
+        write!(f, "{}", outcome)
+    }
+}
+
+/// The number of seconds the server asks to wait in `text`, from either
+/// "You have 1m 5s left to wait" or "please wait one minute".
+fn parse_wait(text: &str) -> Option<u64> {
+    fn number(word: &str) -> Option<u64> {
+        const WORDS: [&str; 10] = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+        ];
+
+        word.parse().ok().or_else(|| {
+            WORDS
+                .iter()
+                .position(|&name| name == word)
+                .map(|idx| idx as u64 + 1)
+        })
+    }
+
+    if let Some(idx) = text.find("You have ") {
+        let rest = &text[idx + "You have ".len()..];
+        let rest = &rest[..rest.find(" left to wait")?];
+
+        return rest.split_whitespace().try_fold(0, |total, amount| {
+            let (value, unit) = amount.split_at(amount.find(|ch: char| !ch.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+
+            match unit {
+                "h" => Some(total + value * 3600),
+                "m" => Some(total + value * 60),
+                "s" => Some(total + value),
+                _ => None,
+            }
+        });
+    }
+
+    let lower = text.to_lowercase();
+    let idx = lower.find("wait ")?;
+    let mut words = lower[idx + "wait ".len()..].split_whitespace();
+    let value = number(words.next()?)?;
+
+    match words.next()? {
+        unit if unit.starts_with("minute") => Some(value * 60),
+        unit if unit.starts_with("second") => Some(value),
+        _ => None,
+    }
+}
+
+impl Verdict {
+    /// Judges the page returned by the server. Only the `<article>` is
+    /// looked at, so the rest of the page cannot cause false matches.
+    pub fn parse(page: &str) -> Verdict {
+        let article = match (page.find("<article"), page.find("</article>")) {
+            (Some(start), Some(end)) if start < end => &page[start..end],
+            _ => page,
+        };
+
+        let outcome = if article.contains("That's the right answer") {
+            Outcome::Correct
+        } else if article.contains("You gave an answer too recently") {
+            Outcome::Throttled
+        } else if article.contains("You don't seem to be solving the right level") {
+            Outcome::WrongLevel
+        } else if article.contains("That's not the right answer") {
+            if article.contains("your answer is too high") {
+                Outcome::TooHigh
+            } else if article.contains("your answer is too low") {
+                Outcome::TooLow
+            } else {
+                Outcome::Wrong
+            }
+        } else {
+            Outcome::Unknown
+        };
+
+        let wait = match outcome {
+            Outcome::Correct | Outcome::WrongLevel | Outcome::Unknown => None,
+            _ => parse_wait(article),
+        };
+
+        Verdict { outcome, wait }
+    }
+}
+
+impl History {
+    /// Reads the history at `path`. A missing file is an empty history.
+    pub fn load(path: impl Into<PathBuf>) -> Result<History> {
+        let path = path.into();
+
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(Error::new(err.to_string()).in_file(&path)),
+        };
+
+        let attempts = contents
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(idx, line)| {
+                serde_json::from_str(line).map_err(|err| {
+                    Error::new(err.to_string()).at(idx + 1, 1).in_file(&path)
+                })
+            })
+            .collect::<Result<_>>()?;
+
+        Ok(History { path, attempts })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Appends `attempt` to the file, so a crash never loses earlier ones.
+    pub fn record(&mut self, attempt: Attempt) -> Result<()> {
+        let io_error = |err: std::io::Error| Error::new(err.to_string()).in_file(&self.path);
+        let line = serde_json::to_string(&attempt).expect("attempts are serializable");
+
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        writeln!(file, "{}", line).map_err(io_error)?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Why `answer` must not be submitted for `part` of `day` at `now`, if it
+    /// must not: the part is already solved, the same answer was already
+    /// rejected, a previous "too high" or "too low" rules it out, or the
+    /// server asked to wait.
+    pub fn check(&self, day: u8, part: Part, answer: &str, now: u64) -> Result<()> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part.number())
+            .collect::<Vec<_>>();
+        let number = answer.parse::<i128>().ok();
+
+        for attempt in &attempts {
+            let previous = attempt.answer.parse::<i128>().ok();
+            let refused = |reason: &str| {
+                Err(Error::new(format!(
+                    "not submitting {} for day {} part {}: {}",
+                    answer, day, part, reason
+                )))
+            };
+
+            match attempt.outcome {
+                Outcome::Correct => {
+                    return refused(&format!("already solved with {}", attempt.answer));
+                }
+                Outcome::TooHigh | Outcome::TooLow | Outcome::Wrong
+                    if attempt.answer == answer =>
+                {
+                    return refused(&format!("already rejected as {}", attempt.outcome));
+                }
+                Outcome::TooHigh if matches!((number, previous), (Some(n), Some(p)) if n >= p) => {
+                    return refused(&format!("{} was already too high", attempt.answer));
+                }
+                Outcome::TooLow if matches!((number, previous), (Some(n), Some(p)) if n <= p) => {
+                    return refused(&format!("{} was already too low", attempt.answer));
+                }
+                _ => (),
+            }
+        }
+
+        let retry_after = attempts.iter().filter_map(|attempt| attempt.retry_after).max();
+        match retry_after {
+            Some(retry_after) if retry_after > now => Err(Error::new(format!(
+                "the server asked to wait, try again in {}s",
+                retry_after - now
+            ))),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Submits `answer` unless the history rules it out, and records the attempt.
+pub fn submit(
+    client: &Client,
+    history: &mut History,
+    day: u8,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Attempt> {
+    history.check(day, part, answer, now)?;
+
+    let page = client.post_answer(day, part, answer)?;
+    let verdict = Verdict::parse(&page);
+
+    let attempt = Attempt {
+        day,
+        part: part.number(),
+        answer: answer.to_string(),
+        outcome: verdict.outcome,
+        at: now,
+        retry_after: verdict.wait.map(|wait| now + wait),
+    };
+    history.record(attempt.clone())?;
+
+    Ok(attempt)
+}
@@ -0,0 +1,213 @@
+//! `aoc watch`: re-runs a day whenever its sources or inputs change, showing
+//! how the answers moved since the previous run.
+//!
+//! The runner cannot reload its own solutions, so every run goes through
+//! `cargo run`, which rebuilds whatever changed first.
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::mpsc;
+use std::time::Duration;
+
+use clap::Args;
+use notify::{Event, RecursiveMode, Watcher};
+use utils::config::input_root;
+use utils::{Error, Part, Result};
+
+use crate::scaffold::{parse_crate_name, workspace_root};
+
+/// How long to wait for more changes once one arrived, since editors tend to
+/// write a file in several steps.
+const SETTLE: Duration = Duration::from_millis(200);
+
+#[derive(Args)]
+pub struct WatchArgs {
+    /// Day of the calendar (1-25)
+    day: u8,
+
+    /// Only run this part, defaults to every registered part
+    part: Option<Part>,
+}
+
+/// The answer of one part on one input, or why there is none.
+type Outcome = std::result::Result<String, String>;
+
+/// Every input to run: the examples, then the real input, by name.
+fn input_names(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter_map(|name| name.strip_suffix(".txt").map(str::to_string))
+        .filter(|name| name.starts_with("example") || name == "input")
+        .collect();
+
+    names.sort_by_key(|name| (name == "input", name.clone()));
+    names
+}
+
+/// The directories holding the sources of `day`, and `utils` which every
+/// day builds on.
+fn source_dirs(root: &Path, day: u8) -> Vec<PathBuf> {
+    let challenges = fs::read_dir(root.join("challenges"))
+        .into_iter()
+        .flatten()
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+            parse_crate_name(&name) == Some(day)
+        })
+        .map(|entry| entry.path());
+
+    challenges.chain([root.join("utils/src")]).collect()
+}
+
+/// Cargo, in the workspace at `root`: the one running us if any.
+fn cargo(root: &Path) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.current_dir(root);
+    command
+}
+
+/// Runs `part` of `day` on the input called `name` through `cargo run`.
+fn run_once(root: &Path, day: u8, part: Part, name: &str) -> Outcome {
+    let output = cargo(root)
+        .args(["run", "--quiet", "-p", "aoc", "--", "run"])
+        .args([day.to_string(), part.to_string(), name.to_string()])
+        .output()
+        .map_err(|err| format!("cannot run cargo: {}", err))?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    match stdout.lines().last().and_then(|line| line.split_once(": ")) {
+        Some((_, answer)) if output.status.success() => Ok(answer.to_string()),
+        _ => {
+            let reason = stderr.trim().lines().last().unwrap_or("failed");
+            Err(reason.trim_start_matches("error: ").to_string())
+        }
+    }
+}
+
+/// Checks that the day and its parts build, printing the compiler's output
+/// if they do not.
+fn build(root: &Path) -> bool {
+    cargo(root)
+        .args(["build", "--quiet", "-p", "aoc"])
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// The files that `event` created, modified or removed.
+fn changed_paths(event: notify::Result<Event>) -> Vec<PathBuf> {
+    match event {
+        Ok(event) if !event.kind.is_access() && !event.kind.is_other() => event.paths,
+        _ => Vec::new(),
+    }
+}
+
+fn describe(outcome: &Outcome) -> String {
+    match outcome {
+        Ok(answer) => answer.clone(),
+        Err(err) => format!("error: {}", err),
+    }
+}
+
+/// Runs every part on every input, and prints the answers next to the ones
+/// of `previous`, which is then updated.
+fn run_all(root: &Path, day: u8, parts: &[Part], previous: &mut BTreeMap<(u8, String), Outcome>) {
+    if !build(root) {
+        println!("Build failed, waiting for the next change");
+        return;
+    }
+
+    let inputs = input_names(&input_root().join(day.to_string()));
+    if inputs.is_empty() {
+        println!(
+            "No inputs in {}",
+            input_root().join(day.to_string()).display()
+        );
+    }
+
+    for &part in parts {
+        for name in &inputs {
+            let outcome = run_once(root, day, part, name);
+            let key = (part.number(), name.clone());
+            let change = match previous.get(&key) {
+                None => String::new(),
+                Some(before) if *before == outcome => " (unchanged)".to_string(),
+                Some(before) => format!(" (was {})", describe(before)),
+            };
+
+            println!(
+                "Day {:>2} part {} {:<10} {}{}",
+                day,
+                part,
+                name,
+                describe(&outcome),
+                change
+            );
+            previous.insert(key, outcome);
+        }
+    }
+}
+
+pub fn watch(args: WatchArgs) -> Result<()> {
+    let root = workspace_root()?;
+    let parts: Vec<Part> = aoc::registry()
+        .entries()
+        .into_iter()
+        .filter(|&(day, part)| day == args.day && args.part.is_none_or(|only| only == part))
+        .map(|(_, part)| part)
+        .collect();
+    if parts.is_empty() {
+        let part = args
+            .part
+            .map(|part| format!(" part {}", part))
+            .unwrap_or_default();
+        return Err(Error::new(format!(
+            "no solution registered for day {}{}",
+            args.day, part
+        )));
+    }
+
+    let (tx, rx) = mpsc::channel::<notify::Result<Event>>();
+    let mut watcher = notify::recommended_watcher(tx).map_err(|err| Error::new(err.to_string()))?;
+
+    let inputs = input_root().join(args.day.to_string());
+    for dir in source_dirs(&root, args.day).iter().chain([&inputs]) {
+        watcher
+            .watch(dir, RecursiveMode::Recursive)
+            .map_err(|err| Error::new(err.to_string()).in_file(dir))?;
+        println!("Watching {}", dir.display());
+    }
+
+    let mut previous = BTreeMap::new();
+    run_all(&root, args.day, &parts, &mut previous);
+
+    loop {
+        let mut changed = Vec::new();
+
+        let event = rx.recv().map_err(|err| Error::new(err.to_string()))?;
+        changed.extend(changed_paths(event));
+        while let Ok(event) = rx.recv_timeout(SETTLE) {
+            changed.extend(changed_paths(event));
+        }
+        // Reading the inputs also makes events, which must not cause a run.
+        if changed.is_empty() {
+            continue;
+        }
+        changed.sort();
+        changed.dedup();
+
+        println!();
+        for path in &changed {
+            println!("Changed {}", path.display());
+        }
+        run_all(&root, args.day, &parts, &mut previous);
+    }
+}
@@ -0,0 +1,30 @@
+//! The only test of this binary, since the allocation counters are shared by
+//! every thread.
+
+use std::hint::black_box;
+
+use utils::alloc::{self, Counting};
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+#[test]
+fn allocations_and_peak_are_counted() {
+    let (kept, stats) = alloc::measure(|| {
+        let dropped = black_box(vec![0_u8; 4096]);
+        drop(dropped);
+
+        let mut kept: Vec<u64> = Vec::with_capacity(64);
+        kept.extend(0..128);
+        black_box(kept)
+    });
+
+    assert_eq!(kept.len(), 128);
+    assert_eq!(stats.allocations, 3);
+    assert_eq!(stats.allocated_bytes, 4096 + 64 * 8 + 128 * 8);
+    assert_eq!(stats.peak_bytes, 4096);
+
+    let (_, stats) = alloc::measure(|| drop(kept));
+    assert_eq!(stats.allocations, 0);
+    assert_eq!(stats.peak_bytes, 0);
+}
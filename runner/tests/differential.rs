@@ -0,0 +1,127 @@
+//! Feeds generated inputs to the straightforward and the optimized solution of
+//! the same question, and checks that they agree:
+//!
+//! - day 5: mapping every seed on its own (part one, with the seed ranges
+//!   spelled out) against mapping whole ranges (part two);
+//! - day 6: the integer race (part one) against the float race (part two),
+//!   on a single race so both read the same numbers;
+//! - day 8: walking from `AAA` (part one) against the lcm of the cycles (part
+//!   two) when `AAA` is the only start, and the lcm against walking every
+//!   start at once on small networks.
+
+use std::collections::HashMap;
+
+use proptest::prelude::*;
+use utils::generate::{Almanac, InputGenerator, Network, Races};
+use utils::Solver;
+
+fn part_one<S: Solver>(solver: S, input: &str) -> String {
+    let parsed = solver.parse(input).expect("generated input must parse");
+    solver.part_one(&parsed).expect("part one failed").to_string()
+}
+
+fn part_two<S: Solver>(solver: S, input: &str) -> String {
+    let parsed = solver.parse(input).expect("generated input must parse");
+    solver.part_two(&parsed).expect("part two failed").to_string()
+}
+
+/// The almanac with its `seeds:` line of start/size pairs replaced by every
+/// seed of those ranges.
+fn spell_out_seeds(input: &str) -> String {
+    let (seeds_line, maps) = input.split_once('\n').unwrap();
+    let numbers: Vec<u64> = seeds_line
+        .trim_start_matches("seeds:")
+        .split_whitespace()
+        .map(|number| number.parse().unwrap())
+        .collect();
+
+    let seeds: Vec<String> = numbers
+        .chunks(2)
+        .flat_map(|pair| pair[0]..pair[0] + pair[1])
+        .map(|seed| seed.to_string())
+        .collect();
+
+    format!("seeds: {}\n{}", seeds.join(" "), maps)
+}
+
+/// Steps until every start node is on a node ending with `Z` at once.
+fn walk_all(input: &str) -> u64 {
+    let (directions, nodes) = input.split_once("\n\n").unwrap();
+    let nodes: HashMap<&str, (&str, &str)> = nodes
+        .lines()
+        .map(|line| {
+            let (node, pair) = line.split_once(" = ").unwrap();
+            let (left, right) = pair.trim_matches(['(', ')']).split_once(", ").unwrap();
+            (node, (left, right))
+        })
+        .collect();
+
+    let mut current: Vec<&str> = nodes.keys().filter(|node| node.ends_with('A')).copied().collect();
+    let mut steps = 0;
+
+    for direction in directions.trim().chars().cycle() {
+        if current.iter().all(|node| node.ends_with('Z')) {
+            break;
+        }
+        for node in &mut current {
+            let (left, right) = nodes[node];
+            *node = if direction == 'L' { left } else { right };
+        }
+        steps += 1;
+    }
+
+    steps
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn day5_ranges_match_single_seeds(seed: u64, ranges in 1..20_usize, seed_ranges in 1..8_usize) {
+        let almanac = Almanac {
+            seed_ranges,
+            ranges,
+            max_value: 1000,
+        };
+        let input = almanac.generate(seed);
+
+        prop_assert_eq!(
+            part_two(ex5::Solution, &input),
+            part_one(ex5::Solution, &spell_out_seeds(&input)),
+            "input:\n{}", input
+        );
+    }
+
+    #[test]
+    fn day6_float_race_matches_integer_race(seed: u64, max_time in 4..60_000_u32) {
+        let input = Races { races: 1, max_time }.generate(seed);
+
+        prop_assert_eq!(
+            part_two(ex6::Solution, &input),
+            part_one(ex6::Solution, &input),
+            "input:\n{}", input
+        );
+    }
+
+    #[test]
+    fn day8_single_cycle_matches_walk(seed: u64, directions in 1..80_usize) {
+        let input = Network { directions, ghosts: 1 }.generate(seed);
+
+        prop_assert_eq!(
+            part_two(ex8::Solution, &input),
+            part_one(ex8::Solution, &input),
+            "input:\n{}", input
+        );
+    }
+
+    #[test]
+    fn day8_lcm_matches_walking_every_start(seed: u64, directions in 1..6_usize, ghosts in 1..4_usize) {
+        let input = Network { directions, ghosts }.generate(seed);
+
+        prop_assert_eq!(
+            part_two(ex8::Solution, &input),
+            walk_all(&input).to_string(),
+            "input:\n{}", input
+        );
+    }
+}
@@ -0,0 +1,71 @@
+mod common;
+
+use std::fs;
+
+use aoc::client::Client;
+use common::MockServer;
+
+const INPUT: &str = "0 3 6 9 12 15\n1 3 6 10 15 21\n";
+
+#[test]
+fn downloads_input_with_session_cookie() {
+    let server = MockServer::start(|_| (200, INPUT.to_string()));
+    let root = tempfile::tempdir().unwrap();
+
+    let client = Client::new(&server.url, "secret");
+    let (path, downloaded) = client.download_input(9, root.path()).unwrap();
+
+    assert!(downloaded);
+    assert_eq!(path, root.path().join("9/input.txt"));
+    assert_eq!(fs::read_to_string(&path).unwrap(), INPUT);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2023/day/9/input");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+}
+
+#[test]
+fn cached_input_is_not_downloaded_again() {
+    let server = MockServer::start(|_| (200, INPUT.to_string()));
+    let root = tempfile::tempdir().unwrap();
+    let client = Client::new(&server.url, "secret");
+
+    client.download_input(9, root.path()).unwrap();
+    let (_, downloaded) = client.download_input(9, root.path()).unwrap();
+
+    assert!(!downloaded);
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn existing_input_is_never_requested() {
+    let server = MockServer::start(|_| (200, "from the server\n".to_string()));
+    let root = tempfile::tempdir().unwrap();
+    fs::create_dir(root.path().join("4")).unwrap();
+    fs::write(root.path().join("4/input.txt"), "copied by hand\n").unwrap();
+
+    let client = Client::new(&server.url, "secret");
+    let (path, downloaded) = client.download_input(4, root.path()).unwrap();
+
+    assert!(!downloaded);
+    assert_eq!(fs::read_to_string(path).unwrap(), "copied by hand\n");
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn rejected_request_is_an_error_and_caches_nothing() {
+    let server = MockServer::start(|_| {
+        let body = "Puzzle inputs differ by user.  Please log in to get your puzzle input.\n";
+        (400, body.to_string())
+    });
+    let root = tempfile::tempdir().unwrap();
+
+    let client = Client::new(&server.url, "expired");
+    let err = client.download_input(2, root.path()).unwrap_err();
+
+    assert!(err.message.contains("HTTP 400"), "{}", err);
+    assert!(err.message.contains("Please log in"), "{}", err);
+    assert!(!root.path().join("2/input.txt").exists());
+}
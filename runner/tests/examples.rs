@@ -0,0 +1,132 @@
+//! Runs every `inputs/<day>/example*.txt` through the registry and compares
+//! the answers with the ones listed for it in `inputs/<day>/answers.toml`.
+//!
+//! Parts without an answer in the manifest are not run, so an example that
+//! only makes sense for one part can leave the other out.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+use utils::config::INPUT_ROOT_VAR;
+use utils::{Answers, Input, Part, Registry};
+
+fn inputs_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../inputs")
+}
+
+/// Every example file of `day` under `root`, sorted by name.
+fn examples(root: &Path, day: u8) -> Vec<PathBuf> {
+    let Ok(entries) = fs::read_dir(root.join(day.to_string())) else {
+        return Vec::new();
+    };
+
+    let mut examples: Vec<PathBuf> = entries
+        .map(|entry| entry.expect("cannot read inputs directory").path())
+        .filter(|path| {
+            let name = path.file_name().and_then(|name| name.to_str()).unwrap_or("");
+            name.starts_with("example") && name.ends_with(".txt")
+        })
+        .collect();
+
+    examples.sort();
+    examples
+}
+
+/// Solves the examples of `days` under `root` and describes every answer
+/// that does not match. Also tells how many answers were checked.
+fn check_examples(registry: &Registry, root: &Path, days: &[u8]) -> (usize, Vec<String>) {
+    let mut checked = 0;
+    let mut failures = Vec::new();
+
+    for &day in days {
+        let answers = match Answers::load(root.join(format!("{}/answers.toml", day))) {
+            Ok(answers) => answers,
+            Err(err) => {
+                failures.push(err.to_string());
+                continue;
+            }
+        };
+
+        for example in examples(root, day) {
+            let name = example.file_stem().and_then(|name| name.to_str()).unwrap_or("");
+            for part in Part::ALL {
+                let Some(answer) = answers.get(name, part) else {
+                    continue;
+                };
+
+                let result = Input::open(&example)
+                    .and_then(|input| registry.solve(day, part, &input))
+                    .map_err(|err| err.in_file(&example));
+                checked += 1;
+
+                match result {
+                    Ok(found) if found == answer => (),
+                    Ok(found) => failures.push(format!(
+                        "{} part {}: expected {}, found {}",
+                        example.display(),
+                        part,
+                        answer,
+                        found
+                    )),
+                    Err(err) => failures.push(format!("{} part {}: {}", example.display(), part, err)),
+                }
+            }
+        }
+    }
+
+    (checked, failures)
+}
+
+#[test]
+fn examples_match_expected_answers() {
+    let registry = aoc::registry();
+    let (checked, failures) = check_examples(&registry, &inputs_root(), &registry.days());
+
+    assert!(failures.is_empty(), "{} example(s) failed:\n{}", failures.len(), failures.join("\n"));
+    assert!(checked > 0, "no examples found under {}", inputs_root().display());
+}
+
+/// `aoc new` writes the crate and registers it, and leaves an empty example
+/// and no answers, which must not fail the tests before the day is solved.
+/// Whether the new crate builds is left to the build of the workspace.
+#[test]
+fn scaffolded_day_passes_until_answers_are_added() {
+    let workspace = tempfile::tempdir().unwrap();
+    let root = workspace.path();
+    let runner = Path::new(env!("CARGO_MANIFEST_DIR"));
+
+    fs::write(root.join("Cargo.toml"), "[workspace]\n").unwrap();
+    fs::create_dir_all(root.join("runner/src")).unwrap();
+    fs::copy(runner.join("Cargo.toml"), root.join("runner/Cargo.toml")).unwrap();
+    fs::copy(runner.join("src/lib.rs"), root.join("runner/src/lib.rs")).unwrap();
+
+    let status = Command::new(env!("CARGO_BIN_EXE_aoc"))
+        .args(["new", "10"])
+        .current_dir(root)
+        .env(INPUT_ROOT_VAR, root.join("inputs"))
+        .stdout(Stdio::null())
+        .status()
+        .unwrap();
+    assert!(status.success());
+
+    let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+    let manifest = read("challenges/ex10/Cargo.toml");
+    assert!(manifest.contains("name = \"ex10\""), "{}", manifest);
+    assert!(!manifest.contains("[profile"), "{}", manifest);
+    assert!(read("challenges/ex10/src/lib.rs").contains("const DAY: u8 = 10;"));
+    assert!(read("challenges/ex10/src/main.rs").contains("run_main(&Solution)"));
+
+    let dependency = r#"ex10 = { version = "0.1.0", path = "../challenges/ex10" }"#;
+    assert!(read("runner/Cargo.toml").lines().any(|line| line == dependency));
+    let registry = read("runner/src/lib.rs");
+    assert!(registry.contains(".register(ex9::Solution)\n        .register(ex10::Solution);"), "{}", registry);
+
+    assert_eq!(examples(&root.join("inputs"), 10).len(), 1);
+    let answers = Answers::load(root.join("inputs/10/answers.toml")).unwrap();
+    assert_eq!(answers.get("example", Part::One), None);
+    assert_eq!(answers.get("example", Part::Two), None);
+
+    let (_, failures) = check_examples(&aoc::registry(), &root.join("inputs"), &[10]);
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
@@ -0,0 +1,22 @@
+//! Every generated input must be one the solutions accept.
+
+use utils::generate::{self, DAYS};
+
+#[test]
+fn generated_inputs_solve_and_are_reproducible() {
+    let registry = aoc::registry();
+
+    for day in DAYS {
+        for seed in 0..4 {
+            let input = generate::generate(day, None, seed).expect("every listed day has a generator");
+            assert_eq!(generate::generate(day, None, seed).as_deref(), Some(input.as_str()));
+
+            let parts = registry.entries().into_iter().filter(|&(entry_day, _)| entry_day == day);
+            for (_, part) in parts {
+                if let Err(err) = registry.solve(day, part, &input) {
+                    panic!("day {} part {} seed {}: {}\n{}", day, part, seed, err, input);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,96 @@
+//! Input that parses but cannot be solved, or cannot be parsed at all, must
+//! be reported as an error rather than a panic.
+
+use utils::{Part, Solver};
+
+/// Parses `input` and solves `part`, describing the first error.
+fn solve<S: Solver>(solver: S, input: &str, part: Part) -> Result<String, String> {
+    let parsed = solver.parse(input).map_err(|err| err.to_string())?;
+    let answer = solver.solve(&parsed, part).map_err(|err| err.to_string())?;
+    Ok(answer.to_string())
+}
+
+#[test]
+fn unwinnable_race_has_no_ways_to_win() {
+    let races = "Time: 7 1\nDistance: 9 5\n";
+
+    assert_eq!(solve(ex6::Solution, races, Part::One), Ok("0".to_string()));
+    assert_eq!(solve(ex6::Solution, races, Part::Two), Ok("68".to_string()));
+}
+
+#[test]
+fn overflowing_answers_are_errors() {
+    let numbers = (1..=34).map(|n| n.to_string()).collect::<Vec<_>>().join(" ");
+    let card = format!("Card 1: {} | {}\n", numbers, numbers);
+    assert_eq!(
+        solve(ex4::Solution, &card, Part::One),
+        Err("card 1 is worth too many points".to_string())
+    );
+
+    let sequence = "2147483647 -2147483648\n";
+    for part in Part::ALL {
+        assert_eq!(
+            solve(ex9::Solution, sequence, part),
+            Err("sequence 1: values do not fit in 32 bits".to_string())
+        );
+    }
+
+    let game = "Game 1: 100000 red, 100000 green, 1 blue\n";
+    assert_eq!(
+        solve(ex2::Solution, game, Part::Two),
+        Err("the total does not fit in 32 bits".to_string())
+    );
+
+    let gear = "99999*99999\n";
+    assert_eq!(
+        solve(ex3::Solution, gear, Part::Two),
+        Err("the total does not fit in 32 bits".to_string())
+    );
+
+    let races = "Time: 4294967296 4294967296 4294967296\nDistance: 1 1 1\n";
+    assert_eq!(
+        solve(ex6::Solution, races, Part::One),
+        Err("the product of the ranges does not fit in 64 bits".to_string())
+    );
+}
+
+#[test]
+fn header_only_sections_are_errors() {
+    assert!(ex5::Solution.parse("seed-to-soil map:\n").is_err());
+    assert!(ex8::Solution.parse("L:\n").is_err());
+}
+
+#[test]
+fn ranges_beyond_u64_are_rejected() {
+    let maps = [
+        "seed-to-soil map:\n0 18446744073709551610 10",
+        "soil-to-fertilizer map:",
+        "fertilizer-to-water map:",
+        "water-to-light map:",
+        "light-to-temperature map:",
+        "temperature-to-humidity map:",
+        "humidity-to-location map:",
+    ]
+    .join("\n\n");
+
+    let err = ex5::Solution.parse(&format!("seeds: 1 2\n\n{}\n", maps)).err().unwrap();
+    assert_eq!(err.to_string(), "4:1: range ends beyond the largest 64-bit number");
+
+    let seeds = format!("seeds: 18446744073709551610 10\n\n{}\n", maps.replace(" 18446744073709551610 ", " 0 "));
+    assert_eq!(
+        solve(ex5::Solution, &seeds, Part::Two),
+        Err("seed range 1 ends beyond the largest 64-bit number".to_string())
+    );
+}
+
+#[test]
+fn unreachable_goals_are_errors() {
+    let network = "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)\n";
+
+    for part in Part::ALL {
+        assert_eq!(
+            solve(ex8::Solution, network, part),
+            Err("no goal can be reached from AAA".to_string())
+        );
+    }
+}
@@ -0,0 +1,129 @@
+mod common;
+
+use aoc::client::Client;
+use aoc::submit::{self, History, Outcome, Verdict};
+use common::MockServer;
+use utils::Part;
+
+const NOW: u64 = 1_701_388_800;
+
+fn page(message: &str) -> String {
+    format!(
+        "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>\n",
+        message
+    )
+}
+
+const CORRECT: &str = "That's the right answer!  You are one gold star closer to restoring snow operations.";
+const TOO_HIGH: &str = "That's not the right answer; your answer is too high.  Please wait one minute before trying again.";
+const TOO_LOW: &str = "That's not the right answer; your answer is too low.  Please wait one minute before trying again.";
+const TOO_RECENTLY: &str = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.";
+
+#[test]
+fn verdicts_are_read_from_the_article() {
+    let correct = Verdict::parse(&page(CORRECT));
+    assert_eq!(correct.outcome, Outcome::Correct);
+    assert_eq!(correct.wait, None);
+
+    let too_high = Verdict::parse(&page(TOO_HIGH));
+    assert_eq!(too_high.outcome, Outcome::TooHigh);
+    assert_eq!(too_high.wait, Some(60));
+
+    assert_eq!(Verdict::parse(&page(TOO_LOW)).outcome, Outcome::TooLow);
+
+    let wrong = Verdict::parse(&page(
+        "That's not the right answer.  Please wait 5 minutes before trying again.",
+    ));
+    assert_eq!(wrong.outcome, Outcome::Wrong);
+    assert_eq!(wrong.wait, Some(300));
+
+    let throttled = Verdict::parse(&page(TOO_RECENTLY));
+    assert_eq!(throttled.outcome, Outcome::Throttled);
+    assert_eq!(throttled.wait, Some(65));
+
+    let level = Verdict::parse(&page(
+        "You don't seem to be solving the right level.  Did you already complete it?",
+    ));
+    assert_eq!(level.outcome, Outcome::WrongLevel);
+
+    let outside = "<p>That's the right answer</p><article><p>Something else</p></article>";
+    assert_eq!(Verdict::parse(outside).outcome, Outcome::Unknown);
+}
+
+#[test]
+fn correct_answer_is_posted_and_recorded() {
+    let server = MockServer::start(|_| (200, page(CORRECT)));
+    let root = tempfile::tempdir().unwrap();
+    let path = root.path().join("submissions.jsonl");
+    let client = Client::new(&server.url, "secret");
+
+    let mut history = History::load(&path).unwrap();
+    let attempt = submit::submit(&client, &mut history, 9, Part::One, "1798691765", NOW).unwrap();
+    assert_eq!(attempt.outcome, Outcome::Correct);
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2023/day/9/answer");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    assert_eq!(requests[0].body, "level=1&answer=1798691765");
+
+    let reloaded = History::load(&path).unwrap();
+    assert_eq!(reloaded.attempts(), [attempt]);
+
+    let err = reloaded.check(9, Part::One, "1798691765", NOW + 3600).unwrap_err();
+    assert!(err.message.contains("already solved"), "{}", err);
+    assert!(reloaded.check(9, Part::Two, "1104", NOW + 3600).is_ok());
+}
+
+#[test]
+fn known_wrong_answers_are_never_sent() {
+    let server = MockServer::start(|request| {
+        if request.body.ends_with("answer=500") {
+            (200, page(TOO_HIGH))
+        } else {
+            (200, page(TOO_LOW))
+        }
+    });
+    let root = tempfile::tempdir().unwrap();
+    let client = Client::new(&server.url, "secret");
+    let mut history = History::load(root.path().join("submissions.jsonl")).unwrap();
+
+    let high = submit::submit(&client, &mut history, 2, Part::Two, "500", NOW).unwrap();
+    assert_eq!(high.outcome, Outcome::TooHigh);
+    assert_eq!(high.retry_after, Some(NOW + 60));
+
+    let later = NOW + 120;
+    for answer in ["500", "501", "9000"] {
+        let err = submit::submit(&client, &mut history, 2, Part::Two, answer, later).unwrap_err();
+        assert!(err.message.contains("not submitting"), "{}", err);
+    }
+    assert_eq!(server.requests().len(), 1);
+
+    let low = submit::submit(&client, &mut history, 2, Part::Two, "100", later).unwrap();
+    assert_eq!(low.outcome, Outcome::TooLow);
+    assert_eq!(server.requests().len(), 2);
+
+    let err = history.check(2, Part::Two, "50", later + 120).unwrap_err();
+    assert!(err.message.contains("too low"), "{}", err);
+    assert!(history.check(2, Part::Two, "300", later + 120).is_ok());
+}
+
+#[test]
+fn server_cooldown_is_respected() {
+    let server = MockServer::start(|_| (200, page(TOO_RECENTLY)));
+    let root = tempfile::tempdir().unwrap();
+    let client = Client::new(&server.url, "secret");
+    let mut history = History::load(root.path().join("submissions.jsonl")).unwrap();
+
+    let attempt = submit::submit(&client, &mut history, 5, Part::One, "42", NOW).unwrap();
+    assert_eq!(attempt.outcome, Outcome::Throttled);
+    assert_eq!(attempt.retry_after, Some(NOW + 65));
+
+    let err = submit::submit(&client, &mut history, 5, Part::One, "42", NOW + 10).unwrap_err();
+    assert!(err.message.contains("try again in 55s"), "{}", err);
+    assert_eq!(server.requests().len(), 1);
+
+    submit::submit(&client, &mut history, 5, Part::One, "42", NOW + 70).unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
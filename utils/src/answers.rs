@@ -0,0 +1,103 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+use crate::solver::Part;
+
+/// The known answers of one day, read from `inputs/<day>/answers.toml`.
+///
+/// Each table is named after an input file without its `.txt` extension and
+/// lists the accepted answer of every part that has one:
+///
+/// ```toml
+/// [input]
+/// part1 = 54630
+/// part2 = 54770
+///
+/// [example]
+/// part1 = 99
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    files: BTreeMap<String, FileAnswers>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileAnswers {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+/// Answers are written as TOML integers when they are numbers, and as strings
+/// otherwise.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Number(i64),
+    Text(String),
+}
+
+impl Answer {
+    fn to_text(&self) -> String {
+        match self {
+            Answer::Number(number) => number.to_string(),
+            Answer::Text(text) => text.clone(),
+        }
+    }
+}
+
+impl Answers {
+    /// Reads a manifest. A missing file is an empty manifest.
+    pub fn load<P>(path: P) -> Result<Answers>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Answers::default()),
+            Err(err) => return Err(Error::new(err.to_string()).in_file(path)),
+        };
+
+        Answers::parse(&contents).map_err(|err| err.in_file(path))
+    }
+
+    pub fn parse(contents: &str) -> Result<Answers> {
+        toml::from_str(contents).map_err(|err| {
+            let error = Error::new(err.message().trim());
+
+            match err.span() {
+                Some(span) => {
+                    let before = &contents[..span.start];
+                    let line = before.matches('\n').count() + 1;
+                    let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+                    error.at(line, before[line_start..].chars().count() + 1)
+                }
+                None => error,
+            }
+        })
+    }
+
+    /// The accepted answer of `part` for the input named `file`.
+    pub fn get(&self, file: &str, part: Part) -> Option<String> {
+        let answers = self.files.get(file)?;
+        let answer = match part {
+            Part::One => answers.part1.as_ref(),
+            Part::Two => answers.part2.as_ref(),
+        };
+
+        answer.map(Answer::to_text)
+    }
+
+    /// The input names that have an entry, in alphabetical order.
+    pub fn files(&self) -> impl Iterator<Item = &str> {
+        self.files.keys().map(String::as_str)
+    }
+}
@@ -0,0 +1,224 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::config::input_root;
+use crate::error::{Error, Result};
+use crate::log;
+use crate::solver::Part;
+
+/// What an answer depends on: the same version of a day's solver always
+/// gives the same answer for the same input. The version can be any string
+/// that changes along with the solver, such as a hash of its sources.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub day: u8,
+    pub part: Part,
+    pub version: String,
+    pub input_hash: u64,
+}
+
+impl Key {
+    pub fn new(day: u8, part: Part, version: &str, input: &str) -> Key {
+        Key {
+            day,
+            part,
+            version: version.to_string(),
+            input_hash: hash(input.as_bytes()),
+        }
+    }
+}
+
+/// 64-bit FNV-1a, which unlike the std hashers is the same on every build.
+fn hash(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// Answers of earlier runs, so that days whose input and solver did not change
+/// need not be solved again.
+///
+/// The cache is a file with one tab-separated `day part version hash answer`
+/// line per answer. A line that cannot be read is skipped, since the answer
+/// can always be computed again.
+#[derive(Debug, Default)]
+pub struct Cache {
+    path: PathBuf,
+    answers: BTreeMap<Key, String>,
+    changed: bool,
+}
+
+impl Cache {
+    /// `answer_cache.tsv` under the input root.
+    pub fn default_path() -> PathBuf {
+        input_root().join("answer_cache.tsv")
+    }
+
+    /// Reads the cache at `path`. A missing file is an empty cache.
+    pub fn load<P>(path: P) -> Result<Cache>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(Error::new(err.to_string()).in_file(path)),
+        };
+
+        let mut answers = BTreeMap::new();
+        for (idx, line) in contents.lines().enumerate() {
+            match parse_line(line) {
+                Some((key, answer)) => {
+                    answers.insert(key, answer);
+                }
+                None => log::warn!("{}:{}: skipping malformed cache line", path.display(), idx + 1),
+            }
+        }
+
+        Ok(Cache {
+            path: path.to_path_buf(),
+            answers,
+            changed: false,
+        })
+    }
+
+    pub fn get(&self, key: &Key) -> Option<&str> {
+        self.answers.get(key).map(String::as_str)
+    }
+
+    /// Remembers `answer`, forgetting the answers of other versions of the
+    /// same day and part. Answers with tabs or line breaks are not kept.
+    pub fn insert(&mut self, key: Key, answer: String) {
+        if answer.contains(['\t', '\n']) {
+            return;
+        }
+
+        self.answers.retain(|other, _| {
+            other.day != key.day || other.part != key.part || other.version == key.version
+        });
+        self.answers.insert(key, answer);
+        self.changed = true;
+    }
+
+    /// Writes the cache back if anything was inserted.
+    pub fn save(&mut self) -> Result<()> {
+        if !self.changed {
+            return Ok(());
+        }
+
+        let contents: String = self
+            .answers
+            .iter()
+            .map(|(key, answer)| {
+                format!(
+                    "{}\t{}\t{}\t{:016x}\t{}\n",
+                    key.day, key.part, key.version, key.input_hash, answer
+                )
+            })
+            .collect();
+
+        let io_error = |err: io::Error| Error::new(err.to_string()).in_file(&self.path);
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        fs::write(&self.path, contents).map_err(io_error)?;
+        self.changed = false;
+
+        Ok(())
+    }
+}
+
+fn parse_line(line: &str) -> Option<(Key, String)> {
+    let mut fields = line.splitn(5, '\t');
+    let day = fields.next()?.parse().ok()?;
+    let part = fields.next()?.parse().ok()?;
+    let version = fields.next()?.to_string();
+    let input_hash = u64::from_str_radix(fields.next()?, 16).ok()?;
+    let answer = fields.next()?.to_string();
+
+    let key = Key {
+        day,
+        part,
+        version,
+        input_hash,
+    };
+    Some((key, answer))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(version: &str, input: &str) -> Key {
+        Key {
+            day: 9,
+            part: Part::One,
+            version: version.to_string(),
+            input_hash: hash(input.as_bytes()),
+        }
+    }
+
+    #[test]
+    fn answers_survive_a_reload() {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join("cache/answers.tsv");
+
+        let mut cache = Cache::load(&path).unwrap();
+        assert_eq!(cache.get(&key("1", "0 3 6 9 12 15\n")), None);
+
+        cache.insert(key("1", "0 3 6 9 12 15\n"), "18".to_string());
+        cache.save().unwrap();
+
+        let reloaded = Cache::load(&path).unwrap();
+        assert_eq!(reloaded.get(&key("1", "0 3 6 9 12 15\n")), Some("18"));
+        assert_eq!(reloaded.get(&key("1", "1 3 6 10 15 21\n")), None);
+        assert_eq!(reloaded.get(&key("2", "0 3 6 9 12 15\n")), None);
+    }
+
+    #[test]
+    fn new_versions_replace_old_answers() {
+        let mut cache = Cache::default();
+        cache.insert(key("1", "example"), "114".to_string());
+        cache.insert(key("1", "input"), "1798691765".to_string());
+        cache.insert(key("2", "input"), "1798691765".to_string());
+
+        assert_eq!(cache.get(&key("1", "example")), None);
+        assert_eq!(cache.get(&key("1", "input")), None);
+        assert_eq!(cache.get(&key("2", "input")), Some("1798691765"));
+
+        let other_part = Key {
+            part: Part::Two,
+            ..key("1", "input")
+        };
+        cache.insert(other_part.clone(), "1104".to_string());
+        assert_eq!(cache.get(&other_part), Some("1104"));
+        assert_eq!(cache.get(&key("2", "input")), Some("1798691765"));
+    }
+
+    #[test]
+    fn malformed_lines_are_skipped() {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join("answers.tsv");
+
+        let mut cache = Cache::load(&path).unwrap();
+        cache.insert(key("1", "input"), "1798691765".to_string());
+        cache.save().unwrap();
+
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::write(&path, format!("garbage\n9\t3\t1\t00\t5\n{}", contents)).unwrap();
+
+        let reloaded = Cache::load(&path).unwrap();
+        assert_eq!(reloaded.get(&key("1", "input")), Some("1798691765"));
+    }
+
+    #[test]
+    fn answers_with_separators_are_not_kept() {
+        let mut cache = Cache::default();
+        cache.insert(key("1", "input"), "two\nlines".to_string());
+
+        assert_eq!(cache.get(&key("1", "input")), None);
+    }
+}
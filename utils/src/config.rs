@@ -0,0 +1,155 @@
+//! Settings that depend on the machine rather than on the puzzles, read from
+//! environment variables and `~/.config/aoc/config.toml`.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+use crate::error::{Error, Result};
+use crate::log;
+
+/// Overrides the directory holding the `<day>/` input folders.
+pub const INPUT_ROOT_VAR: &str = "AOC_INPUT_ROOT";
+/// Overrides the location of the config file.
+pub const CONFIG_VAR: &str = "AOC_CONFIG";
+/// The adventofcode.com `session` cookie, overriding the config file.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+/// Overrides the server inputs and answers are exchanged with.
+pub const BASE_URL_VAR: &str = "AOC_BASE_URL";
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The contents of the config file. Every setting is optional:
+///
+/// ```toml
+/// input_root = "~/aoc/inputs"
+/// session = "53616c7465645f5f..."
+/// base_url = "https://adventofcode.com"
+/// ```
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Relative paths are taken from the directory of the config file.
+    pub input_root: Option<PathBuf>,
+    /// The `session` cookie of a logged-in browser.
+    pub session: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    /// `$AOC_CONFIG`, else `$XDG_CONFIG_HOME/aoc/config.toml`, else
+    /// `~/.config/aoc/config.toml`.
+    pub fn path() -> Option<PathBuf> {
+        if let Some(path) = env::var_os(CONFIG_VAR) {
+            return Some(PathBuf::from(path));
+        }
+
+        let config_home = env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| home_dir().map(|home| home.join(".config")))?;
+
+        Some(config_home.join("aoc/config.toml"))
+    }
+
+    /// Reads the config file. A missing file is an empty config.
+    pub fn load() -> Result<Config> {
+        match Config::path() {
+            Some(path) => Config::load_from(&path),
+            None => Ok(Config::default()),
+        }
+    }
+
+    /// The session token: `AOC_SESSION`, else `session` in the config file.
+    pub fn session(&self) -> Option<String> {
+        env::var(SESSION_VAR)
+            .ok()
+            .or_else(|| self.session.clone())
+            .map(|session| session.trim().to_string())
+            .filter(|session| !session.is_empty())
+    }
+
+    /// `AOC_BASE_URL`, else `base_url` in the config file, else
+    /// `https://adventofcode.com`.
+    pub fn base_url(&self) -> String {
+        env::var(BASE_URL_VAR)
+            .ok()
+            .or_else(|| self.base_url.clone())
+            .unwrap_or_else(|| DEFAULT_BASE_URL.to_string())
+    }
+
+    pub fn load_from(path: &Path) -> Result<Config> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(err) => return Err(Error::new(err.to_string()).in_file(path)),
+        };
+
+        let mut config: Config = toml::from_str(&contents)
+            .map_err(|err| Error::new(err.message().trim()).in_file(path))?;
+
+        let base = path.parent().unwrap_or(Path::new(""));
+        config.input_root = config.input_root.map(|root| base.join(expand_home(&root)));
+
+        Ok(config)
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME").map(PathBuf::from)
+}
+
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+/// The first `inputs/` directory found walking up from `start`.
+fn find_inputs(start: &Path) -> Option<PathBuf> {
+    start
+        .ancestors()
+        .map(|dir| dir.join("inputs"))
+        .find(|inputs| inputs.is_dir())
+}
+
+/// The directory holding the `<day>/` input folders, resolved once per
+/// process from, in order:
+///
+/// 1. the `AOC_INPUT_ROOT` environment variable;
+/// 2. `input_root` in the config file;
+/// 3. the nearest `inputs/` directory above the working directory, then above
+///    the running executable;
+/// 4. `inputs` relative to the working directory.
+///
+/// A config file that cannot be read is logged as a warning and skipped.
+pub fn input_root() -> &'static Path {
+    static ROOT: OnceLock<PathBuf> = OnceLock::new();
+
+    ROOT.get_or_init(|| {
+        if let Some(root) = env::var_os(INPUT_ROOT_VAR) {
+            return expand_home(Path::new(&root));
+        }
+
+        match Config::load() {
+            Ok(Config {
+                input_root: Some(root),
+                ..
+            }) => return root,
+            Ok(_) => (),
+            Err(err) => log::warn!("ignoring config: {}", err),
+        }
+
+        let from_cwd = env::current_dir().ok().and_then(|cwd| find_inputs(&cwd));
+        let from_exe = || {
+            let exe = env::current_exe().ok()?;
+            find_inputs(exe.parent()?)
+        };
+
+        from_cwd.or_else(from_exe).unwrap_or_else(|| PathBuf::from("inputs"))
+    })
+}
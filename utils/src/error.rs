@@ -0,0 +1,65 @@
+use std::fmt::{self, Display};
+use std::path::{Path, PathBuf};
+
+/// An error pointing at a position in an input file.
+///
+/// Positions are 1-based, like the ones printed by editors and compilers.
+/// Parsers usually only know the line and column; the file is attached by
+/// whoever loaded the input, see [`Error::in_file`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Error {
+    pub file: Option<PathBuf>,
+    pub line: Option<usize>,
+    pub column: Option<usize>,
+    pub message: String,
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn new(message: impl Into<String>) -> Error {
+        Error {
+            file: None,
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn at(self, line: usize, column: usize) -> Error {
+        Error {
+            line: Some(line),
+            column: Some(column),
+            ..self
+        }
+    }
+
+    /// Attaches `file` to the error, unless it already names one.
+    pub fn in_file(self, file: impl AsRef<Path>) -> Error {
+        Error {
+            file: self.file.or_else(|| Some(file.as_ref().to_path_buf())),
+            ..self
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(file) = &self.file {
+            write!(f, "{}:", file.display())?;
+        }
+        if let Some(line) = self.line {
+            write!(f, "{}:", line)?;
+        }
+        if let Some(column) = self.column {
+            write!(f, "{}:", column)?;
+        }
+        if self.file.is_some() || self.line.is_some() {
+            write!(f, " ")?;
+        }
+
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for Error {}
@@ -0,0 +1,677 @@
+//! Random puzzle inputs, valid for the solutions of each day, to test them on
+//! more than the one real input. The same seed always gives the same input.
+
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use rand::seq::{IndexedRandom, SliceRandom};
+use rand::{Rng as _, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+/// The random number generator behind every generator, chosen because its
+/// output for a given seed does not change across versions.
+pub type Rng = ChaCha8Rng;
+
+/// Writes random valid inputs for one day.
+pub trait InputGenerator {
+    const DAY: u8;
+
+    /// A generator of inputs with `size` records of the day: lines, games,
+    /// cards, races, hands... The other dimensions keep their defaults, which
+    /// are close to the real input.
+    fn with_size(size: usize) -> Self
+    where
+        Self: Sized;
+
+    fn generate_with(&self, rng: &mut Rng) -> String;
+
+    fn generate(&self, seed: u64) -> String {
+        self.generate_with(&mut Rng::seed_from_u64(seed))
+    }
+}
+
+/// An input for `day` from the generator of that day, with its default size
+/// unless `size` is given.
+pub fn generate(day: u8, size: Option<usize>, seed: u64) -> Option<String> {
+    fn run<G: InputGenerator + Default>(size: Option<usize>, seed: u64) -> (u8, String) {
+        (G::DAY, size.map_or_else(G::default, G::with_size).generate(seed))
+    }
+
+    let input = match day {
+        1 => run::<Calibration>(size, seed),
+        2 => run::<Games>(size, seed),
+        3 => run::<Schematic>(size, seed),
+        4 => run::<Scratchcards>(size, seed),
+        5 => run::<Almanac>(size, seed),
+        6 => run::<Races>(size, seed),
+        7 => run::<CamelCards>(size, seed),
+        8 => run::<Network>(size, seed),
+        9 => run::<Sequences>(size, seed),
+        _ => return None,
+    };
+    debug_assert_eq!(input.0, day, "generator listed under the wrong day");
+
+    Some(input.1)
+}
+
+/// The days that have a generator.
+pub const DAYS: [u8; 9] = [1, 2, 3, 4, 5, 6, 7, 8, 9];
+
+const DIGIT_WORDS: [&str; 9] = [
+    "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+];
+
+/// Day 1: lines of letters, digits and spelled-out digits, including
+/// overlapping ones like `twone`. Every line has at least one digit.
+#[derive(Clone, Debug)]
+pub struct Calibration {
+    pub lines: usize,
+    /// Pieces (a letter, a digit or a word) per line.
+    pub max_pieces: usize,
+}
+
+impl Default for Calibration {
+    fn default() -> Calibration {
+        Calibration::with_size(1000)
+    }
+}
+
+impl InputGenerator for Calibration {
+    const DAY: u8 = 1;
+
+    fn with_size(lines: usize) -> Calibration {
+        Calibration {
+            lines,
+            max_pieces: 12,
+        }
+    }
+
+    fn generate_with(&self, rng: &mut Rng) -> String {
+        const OVERLAPS: [&str; 7] = [
+            "twone",
+            "oneight",
+            "threeight",
+            "fiveight",
+            "sevenine",
+            "eightwo",
+            "eighthree",
+        ];
+
+        let mut input = String::new();
+
+        for _ in 0..self.lines {
+            let pieces = rng.random_range(1..=self.max_pieces.max(1));
+            let digit_at = rng.random_range(0..pieces);
+
+            for idx in 0..pieces {
+                match rng.random_range(0..10) {
+                    _ if idx == digit_at => input.push(char::from(b'1' + rng.random_range(0..9))),
+                    0..=4 => input.push(char::from(b'a' + rng.random_range(0..26))),
+                    5..=7 => input.push_str(DIGIT_WORDS.choose(rng).unwrap()),
+                    8 => input.push_str(OVERLAPS.choose(rng).unwrap()),
+                    _ => input.push(char::from(b'1' + rng.random_range(0..9))),
+                }
+            }
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+/// Day 2: games of one to three colored cubes per draw.
+#[derive(Clone, Debug)]
+pub struct Games {
+    pub games: usize,
+    pub max_draws: usize,
+    pub max_count: u32,
+}
+
+impl Default for Games {
+    fn default() -> Games {
+        Games::with_size(100)
+    }
+}
+
+impl InputGenerator for Games {
+    const DAY: u8 = 2;
+
+    fn with_size(games: usize) -> Games {
+        Games {
+            games,
+            max_draws: 6,
+            max_count: 20,
+        }
+    }
+
+    fn generate_with(&self, rng: &mut Rng) -> String {
+        let mut input = String::new();
+
+        for id in 1..=self.games {
+            let draws = (0..rng.random_range(1..=self.max_draws.max(1)))
+                .map(|_| {
+                    let mut colors = ["red", "green", "blue"];
+                    colors.shuffle(rng);
+
+                    let count = rng.random_range(1..=3);
+                    colors[..count]
+                        .iter()
+                        .map(|color| {
+                            format!("{} {}", rng.random_range(1..=self.max_count.max(1)), color)
+                        })
+                        .join(", ")
+                })
+                .join("; ");
+
+            input.push_str(&format!("Game {}: {}\n", id, draws));
+        }
+
+        input
+    }
+}
+
+/// Day 3: a grid of numbers, symbols and dots.
+#[derive(Clone, Debug)]
+pub struct Schematic {
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Default for Schematic {
+    fn default() -> Schematic {
+        Schematic::with_size(140)
+    }
+}
+
+impl InputGenerator for Schematic {
+    const DAY: u8 = 3;
+
+    /// A square schematic of `size` rows and columns.
+    fn with_size(size: usize) -> Schematic {
+        Schematic {
+            width: size,
+            height: size,
+        }
+    }
+
+    fn generate_with(&self, rng: &mut Rng) -> String {
+        const SYMBOLS: &[u8] = b"*#+$/=%@&-";
+
+        let mut input = String::new();
+
+        for _ in 0..self.height {
+            let mut col = 0;
+
+            while col < self.width {
+                let left = self.width - col;
+
+                match rng.random_range(0..20) {
+                    0..=4 => {
+                        let len = rng.random_range(1..=left.min(3));
+                        let number =
+                            rng.random_range(10_u32.pow(len as u32 - 1)..10_u32.pow(len as u32));
+                        input.push_str(&number.to_string());
+                        col += len;
+
+                        // Digits after a number would make it longer.
+                        if col < self.width {
+                            input.push(if rng.random_bool(0.2) {
+                                char::from(*SYMBOLS.choose(rng).unwrap())
+                            } else {
+                                '.'
+                            });
+                            col += 1;
+                        }
+                    }
+                    5 | 6 => {
+                        input.push(char::from(*SYMBOLS.choose(rng).unwrap()));
+                        col += 1;
+                    }
+                    _ => {
+                        input.push('.');
+                        col += 1;
+                    }
+                }
+            }
+            input.push('\n');
+        }
+
+        input
+    }
+}
+
+/// Day 4: cards of distinct winning numbers and distinct numbers, aligned
+/// like the real input. Matches are limited so that the number of cards won
+/// in part two fits in 32 bits.
+#[derive(Clone, Debug)]
+pub struct Scratchcards {
+    pub cards: usize,
+    /// Numbers are below 100, so there are at most 99 of each kind.
+    pub winning: usize,
+    pub numbers: usize,
+}
+
+impl Default for Scratchcards {
+    fn default() -> Scratchcards {
+        Scratchcards::with_size(200)
+    }
+}
+
+impl InputGenerator for Scratchcards {
+    const DAY: u8 = 4;
+
+    fn with_size(cards: usize) -> Scratchcards {
+        Scratchcards {
+            cards,
+            winning: 10,
+            numbers: 25,
+        }
+    }
+
+    fn generate_with(&self, rng: &mut Rng) -> String {
+        let winning_count = self.winning.min(99);
+        let numbers_count = self.numbers.min(99);
+        let id_width = self.cards.to_string().len();
+        let limit = u64::from(u32::MAX) / self.cards.max(1) as u64;
+
+        let mut copies = vec![1_u64; self.cards];
+        let mut input = String::new();
+
+        for card in 0..self.cards {
+            let mut pool = (1..=99_u32).collect_vec();
+            pool.shuffle(rng);
+            let (winning, others) = pool.split_at(winning_count);
+
+            let max_matches = winning_count.min(numbers_count).min(self.cards - card - 1);
+            let mut matches = rng.random_range(0..=max_matches);
+            while copies[card + 1..=card + matches]
+                .iter()
+                .any(|&count| count + copies[card] > limit)
+            {
+                matches -= 1;
+            }
+            for idx in card + 1..=card + matches {
+                copies[idx] += copies[card];
+            }
+
+            let fillers = numbers_count.saturating_sub(matches).min(others.len());
+            let mut numbers = winning[..matches]
+                .iter()
+                .chain(&others[..fillers])
+                .collect_vec();
+            numbers.shuffle(rng);
+
+            input.push_str(&format!(
+                "Card {:>width$}: {} | {}\n",
+                card + 1,
+                winning
+                    .iter()
+                    .map(|number| format!("{:>2}", number))
+                    .join(" "),
+                numbers
+                    .iter()
+                    .map(|number| format!("{:>2}", number))
+                    .join(" "),
+                width = id_width
+            ));
+        }
+
+        input
+    }
+}
+
+/// Day 5: seed ranges and seven maps. Neither the source nor the destination
+/// ranges of a map overlap.
+#[derive(Clone, Debug)]
+pub struct Almanac {
+    /// Pairs of numbers on the seeds line.
+    pub seed_ranges: usize,
+    /// Ranges per map.
+    pub ranges: usize,
+    /// Every number stays below this.
+    pub max_value: u64,
+}
+
+impl Default for Almanac {
+    fn default() -> Almanac {
+        Almanac::with_size(30)
+    }
+}
+
+impl Almanac {
+    const MAPS: [&'static str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+
+    /// Cuts `0..max_value` into segments and lays them out again in random
+    /// order, then lists `ranges` of them; the others map to themselves.
+    fn map(&self, rng: &mut Rng) -> Vec<[u64; 3]> {
+        let mut cuts = (0..self.ranges * 2)
+            .map(|_| rng.random_range(1..self.max_value))
+            .collect::<HashSet<_>>()
+            .into_iter()
+            .chain([0, self.max_value])
+            .collect_vec();
+        cuts.sort_unstable();
+
+        let segments = cuts
+            .iter()
+            .tuple_windows()
+            .map(|(&start, &end)| (start, end - start))
+            .collect_vec();
+        let mut shuffled = segments.clone();
+        shuffled.shuffle(rng);
+
+        let mut destination = 0;
+        let mut ranges = shuffled
+            .into_iter()
+            .map(|(source, size)| {
+                let range = [destination, source, size];
+                destination += size;
+                range
+            })
+            .collect_vec();
+
+        ranges.shuffle(rng);
+        ranges.truncate(self.ranges);
+        ranges
+    }
+}
+
+impl InputGenerator for Almanac {
+    const DAY: u8 = 5;
+
+    /// An almanac with `size` ranges per map.
+    fn with_size(ranges: usize) -> Almanac {
+        Almanac {
+            seed_ranges: 10,
+            ranges,
+            max_value: 1 << 32,
+        }
+    }
+
+    fn generate_with(&self, rng: &mut Rng) -> String {
+        let seeds = (0..self.seed_ranges)
+            .map(|_| {
+                let start = rng.random_range(0..self.max_value - 1);
+                let size =
+                    rng.random_range(1..=(self.max_value - start).min(self.max_value / 20).max(1));
+                format!("{} {}", start, size)
+            })
+            .join(" ");
+
+        let mut input = format!("seeds: {}\n", seeds);
+
+        for name in Almanac::MAPS {
+            input.push_str(&format!("\n{} map:\n", name));
+            for [destination, source, size] in self.map(rng) {
+                input.push_str(&format!("{} {} {}\n", destination, source, size));
+            }
+        }
+
+        input
+    }
+}
+
+/// Day 6: races that can all be won. Part two reads the races as one, so
+/// more than four races or longer times overflow its numbers.
+#[derive(Clone, Debug)]
+pub struct Races {
+    pub races: usize,
+    pub max_time: u32,
+}
+
+impl Default for Races {
+    fn default() -> Races {
+        Races::with_size(4)
+    }
+}
+
+impl InputGenerator for Races {
+    const DAY: u8 = 6;
+
+    fn with_size(races: usize) -> Races {
+        Races {
+            races,
+            max_time: 99,
+        }
+    }
+
+    fn generate_with(&self, rng: &mut Rng) -> String {
+        let races = (0..self.races)
+            .map(|_| {
+                let time = rng.random_range(4..=self.max_time.max(4));
+                // Any hold between `hold` and `time - hold` beats the record.
+                let hold = rng.random_range(1..time / 2);
+                (time.to_string(), (hold * (time - hold)).to_string())
+            })
+            .collect_vec();
+
+        let widths = races
+            .iter()
+            .map(|(time, distance)| time.len().max(distance.len()))
+            .collect_vec();
+        let row = |label: &str, values: Vec<&String>| {
+            let values = values
+                .into_iter()
+                .zip(&widths)
+                .map(|(value, &width)| format!("{:>width$}", value, width = width))
+                .join("  ");
+            format!("{:<9} {}\n", label, values)
+        };
+
+        row("Time:", races.iter().map(|(time, _)| time).collect())
+            + &row(
+                "Distance:",
+                races.iter().map(|(_, distance)| distance).collect(),
+            )
+    }
+}
+
+/// Day 7: distinct hands, with a mix of every hand type, and their bids.
+#[derive(Clone, Debug)]
+pub struct CamelCards {
+    pub hands: usize,
+    pub max_bid: u32,
+}
+
+impl Default for CamelCards {
+    fn default() -> CamelCards {
+        CamelCards::with_size(1000)
+    }
+}
+
+impl InputGenerator for CamelCards {
+    const DAY: u8 = 7;
+
+    fn with_size(hands: usize) -> CamelCards {
+        CamelCards {
+            hands,
+            max_bid: 1000,
+        }
+    }
+
+    fn generate_with(&self, rng: &mut Rng) -> String {
+        const CARDS: [char; 13] = [
+            'A', 'K', 'Q', 'J', 'T', '9', '8', '7', '6', '5', '4', '3', '2',
+        ];
+        // There are only that many distinct hands.
+        let hands = self.hands.min(CARDS.len().pow(5));
+
+        let mut seen = HashSet::with_capacity(hands);
+        let mut input = String::new();
+
+        while seen.len() < hands {
+            // Drawing from a few cards makes pairs, threes and more likely.
+            let pool_size = rng.random_range(1..=5);
+            let pool = CARDS.choose_multiple(rng, pool_size).copied().collect_vec();
+            let hand: String = (0..5).map(|_| *pool.choose(rng).unwrap()).collect();
+
+            if seen.insert(hand.clone()) {
+                input.push_str(&format!(
+                    "{} {}\n",
+                    hand,
+                    rng.random_range(1..=self.max_bid.max(1))
+                ));
+            }
+        }
+
+        input
+    }
+}
+
+/// Day 8: a network where `AAA` reaches `ZZZ`, and every `..A` node reaches
+/// its own `..Z` node after a multiple of the directions' length, then loops
+/// back to the same path, like the real input.
+#[derive(Clone, Debug)]
+pub struct Network {
+    pub directions: usize,
+    /// Start nodes, at most 10. Each loops a different prime number of
+    /// times through the directions before reaching its goal.
+    pub ghosts: usize,
+}
+
+impl Default for Network {
+    fn default() -> Network {
+        Network::with_size(61)
+    }
+}
+
+impl InputGenerator for Network {
+    const DAY: u8 = 8;
+
+    /// A network whose directions are `size` long.
+    fn with_size(directions: usize) -> Network {
+        Network {
+            directions,
+            ghosts: 6,
+        }
+    }
+
+    fn generate_with(&self, rng: &mut Rng) -> String {
+        const PRIMES: [usize; 10] = [3, 5, 7, 11, 13, 17, 19, 23, 29, 31];
+        const ALPHABET: &[u8] = b"BCDEFGHIJKLMNOPQRSTUVWXY0123456789";
+
+        let directions: Vec<bool> = (0..self.directions.max(1))
+            .map(|_| rng.random_bool(0.5))
+            .collect();
+        let loops = PRIMES
+            .choose_multiple(rng, self.ghosts.clamp(1, PRIMES.len()))
+            .copied()
+            .collect_vec();
+
+        // Names of inner nodes never end with A or Z, and get longer when
+        // three characters would not leave enough of them.
+        let nodes = directions.len() * loops.iter().sum::<usize>();
+        let len = if nodes < ALPHABET.len().pow(3) / 4 {
+            3
+        } else {
+            4
+        };
+        let mut names = HashSet::new();
+        let mut name = |rng: &mut Rng| loop {
+            let name: String = (0..len)
+                .map(|_| char::from(*ALPHABET.choose(rng).unwrap()))
+                .collect();
+            if names.insert(name.clone()) {
+                return name;
+            }
+        };
+
+        let mut paths = Vec::with_capacity(loops.len());
+        for (ghost, &laps) in loops.iter().enumerate() {
+            let (start, goal) = match ghost {
+                0 => ("AAA".to_string(), "ZZZ".to_string()),
+                _ => (format!("{0}{0}A", ghost), format!("{0}{0}Z", ghost)),
+            };
+
+            let mut path = vec![start];
+            path.extend((1..directions.len() * laps).map(|_| name(rng)));
+            path.push(goal);
+            paths.push(path);
+        }
+
+        let all_names = paths.iter().flatten().cloned().collect_vec();
+        let mut nodes = Vec::new();
+
+        for path in &paths {
+            // The goal continues like the start, so the path repeats from there.
+            let steps = path.len() - 1;
+            for (step, node) in path.iter().enumerate() {
+                let next = &path[if step == steps { 1 } else { step + 1 }];
+                let decoy = all_names.choose(rng).unwrap();
+                let (left, right) = match directions[step % directions.len()] {
+                    true => (next, decoy),
+                    false => (decoy, next),
+                };
+                nodes.push(format!("{} = ({}, {})", node, left, right));
+            }
+        }
+        nodes.shuffle(rng);
+
+        let directions: String = directions
+            .iter()
+            .map(|&left| if left { 'L' } else { 'R' })
+            .collect();
+        format!("{}\n\n{}\n", directions, nodes.join("\n"))
+    }
+}
+
+/// Day 9: values of integer polynomials.
+#[derive(Clone, Debug)]
+pub struct Sequences {
+    pub sequences: usize,
+    pub length: usize,
+    pub max_degree: usize,
+}
+
+impl Default for Sequences {
+    fn default() -> Sequences {
+        Sequences::with_size(200)
+    }
+}
+
+impl InputGenerator for Sequences {
+    const DAY: u8 = 9;
+
+    fn with_size(sequences: usize) -> Sequences {
+        Sequences {
+            sequences,
+            length: 21,
+            max_degree: 6,
+        }
+    }
+
+    fn generate_with(&self, rng: &mut Rng) -> String {
+        let mut input = String::new();
+
+        for _ in 0..self.sequences {
+            // The first column of the difference table, from the values
+            // down to the constant differences.
+            let degree = rng.random_range(0..=self.max_degree);
+            let mut column = (0..=degree)
+                .map(|_| rng.random_range(-9_i64..=9))
+                .collect_vec();
+            column[0] = rng.random_range(-30..=30);
+
+            let mut values = Vec::with_capacity(self.length);
+            for _ in 0..self.length.max(1) {
+                values.push(column[0]);
+                for idx in 0..degree {
+                    column[idx] += column[idx + 1];
+                }
+            }
+
+            input.push_str(&values.iter().join(" "));
+            input.push('\n');
+        }
+
+        input
+    }
+}
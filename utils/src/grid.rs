@@ -0,0 +1,335 @@
+use std::ops::{Index, IndexMut, Range};
+
+use crate::error::Result;
+use crate::span::lines;
+
+/// A cell coordinate, counted from the top-left corner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Position {
+    pub row: usize,
+    pub col: usize,
+}
+
+/// A horizontal run of adjacent cells in one row, e.g. the digits of a
+/// number in a schematic.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Run {
+    pub row: usize,
+    pub cols: Range<usize>,
+}
+
+/// A rectangular grid of cells stored row by row.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+impl Position {
+    pub fn new(row: usize, col: usize) -> Position {
+        Position { row, col }
+    }
+
+    fn offset(self, (d_row, d_col): (isize, isize)) -> Option<Position> {
+        Some(Position {
+            row: self.row.checked_add_signed(d_row)?,
+            col: self.col.checked_add_signed(d_col)?,
+        })
+    }
+}
+
+impl Run {
+    pub fn len(&self) -> usize {
+        self.cols.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cols.is_empty()
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Position> + '_ {
+        self.cols.clone().map(|col| Position::new(self.row, col))
+    }
+}
+
+impl Grid<char> {
+    /// Builds a grid with one cell per character; every line must have the
+    /// same length.
+    pub fn from_text(input: &str) -> Result<Grid<char>> {
+        Grid::parse(input, |_, ch| Ok(ch))
+    }
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(cells.len(), width * height, "grid size mismatch");
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Builds a grid from text, converting each character with `cell`. Blank
+    /// trailing lines are ignored; rows of different lengths are an error.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<Grid<T>>
+    where
+        F: FnMut(Position, char) -> Result<T>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for line in lines(input.trim_end()) {
+            let row_width = line.as_str().chars().count();
+            let expected = *width.get_or_insert(row_width);
+
+            if row_width != expected {
+                let message = format!("expected {} columns, found {}", expected, row_width);
+                return Err(line.error(message));
+            }
+
+            for (col, (span, ch)) in line.chars().enumerate() {
+                let position = Position::new(height, col);
+                cells.push(cell(position, ch).map_err(|err| match err.line {
+                    Some(_) => err,
+                    None => err.at(span.line(), span.column()),
+                })?);
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, cells))
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.row < self.height && position.col < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        if self.contains(position) {
+            Some(&self.cells[position.row * self.width + position.col])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        if self.contains(position) {
+            Some(&mut self.cells[position.row * self.width + position.col])
+        } else {
+            None
+        }
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.height).flat_map(move |row| (0..width).map(move |col| Position::new(row, col)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    pub fn row(&self, row: usize) -> &[T] {
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        (0..self.height).map(move |row| &self.cells[row * self.width + col])
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    fn neighbours(
+        &self,
+        position: Position,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = Position> + '_ {
+        offsets
+            .iter()
+            .filter_map(move |&offset| position.offset(offset))
+            .filter(|&neighbour| self.contains(neighbour))
+    }
+
+    /// The up to 4 orthogonally adjacent positions inside the grid.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &OFFSETS_4)
+    }
+
+    /// The up to 8 orthogonally or diagonally adjacent positions inside the
+    /// grid.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbours(position, &OFFSETS_8)
+    }
+
+    /// The maximal horizontal runs of cells matching `predicate`, row by row.
+    pub fn runs<P>(&self, mut predicate: P) -> Vec<Run>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let mut runs = Vec::new();
+
+        for (row, cells) in self.rows().enumerate() {
+            let mut start = None;
+
+            for (col, cell) in cells.iter().enumerate() {
+                match (predicate(cell), start) {
+                    (true, None) => start = Some(col),
+                    (false, Some(run_start)) => {
+                        runs.push(Run { row, cols: run_start..col });
+                        start = None;
+                    }
+                    _ => (),
+                }
+            }
+
+            if let Some(run_start) = start {
+                runs.push(Run { row, cols: run_start..self.width });
+            }
+        }
+
+        runs
+    }
+
+    /// The cells of `run`.
+    pub fn run_cells(&self, run: &Run) -> &[T] {
+        &self.row(run.row)[run.cols.clone()]
+    }
+
+    /// The positions surrounding `run`, diagonals included, that are inside
+    /// the grid. Each position is returned once.
+    pub fn run_neighbours(&self, run: &Run) -> impl Iterator<Item = Position> + '_ {
+        let cols = run.cols.start.saturating_sub(1)..(run.cols.end + 1).min(self.width);
+        let rows = run.row.saturating_sub(1)..(run.row + 2).min(self.height);
+        let run = run.clone();
+
+        rows.flat_map(move |row| cols.clone().map(move |col| Position::new(row, col)))
+            .filter(move |position| position.row != run.row || !run.cols.contains(&position.col))
+    }
+
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position).expect("position outside the grid")
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position).expect("position outside the grid")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::Error;
+
+    fn at(row: usize, col: usize) -> Position {
+        Position::new(row, col)
+    }
+
+    fn grid() -> Grid<char> {
+        Grid::from_text("467..114\n...*....\n..35..63\n").unwrap()
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = grid();
+
+        let corner: Vec<_> = grid.neighbours4(at(0, 0)).collect();
+        assert_eq!(corner, [at(0, 1), at(1, 0)]);
+
+        let corner: Vec<_> = grid.neighbours8(at(2, 7)).collect();
+        assert_eq!(corner, [at(1, 6), at(1, 7), at(2, 6)]);
+
+        assert_eq!(grid.neighbours4(at(1, 3)).count(), 4);
+        assert_eq!(grid.neighbours8(at(1, 3)).count(), 8);
+        assert_eq!(grid.neighbours8(at(0, 4)).count(), 5);
+    }
+
+    #[test]
+    fn runs_end_at_the_row_edge() {
+        let grid = grid();
+        let runs = grid.runs(char::is_ascii_digit);
+
+        let expected = [(0, 0..3), (0, 5..8), (2, 2..4), (2, 6..8)];
+        let expected: Vec<_> = expected.into_iter().map(|(row, cols)| Run { row, cols }).collect();
+        assert_eq!(runs, expected);
+
+        let cells: String = grid.run_cells(&runs[1]).iter().collect();
+        assert_eq!(cells, "114");
+    }
+
+    #[test]
+    fn run_neighbours_are_clipped_to_the_grid() {
+        let grid = grid();
+
+        let first: Vec<_> = grid.run_neighbours(&Run { row: 0, cols: 0..3 }).collect();
+        assert_eq!(first, [at(0, 3), at(1, 0), at(1, 1), at(1, 2), at(1, 3)]);
+
+        let last: Vec<_> = grid.run_neighbours(&Run { row: 2, cols: 6..8 }).collect();
+        assert_eq!(last, [at(1, 5), at(1, 6), at(1, 7), at(2, 5)]);
+
+        let middle = Run { row: 1, cols: 3..4 };
+        assert_eq!(grid.run_neighbours(&middle).count(), 8);
+    }
+
+    #[test]
+    fn ragged_rows_are_an_error() {
+        let err = Grid::from_text("abc\nabé\nab\n").unwrap_err();
+
+        assert_eq!((err.line, err.column), (Some(3), Some(1)));
+        assert_eq!(err.message, "expected 3 columns, found 2");
+    }
+
+    #[test]
+    fn cell_errors_point_at_the_character() {
+        let err = Grid::parse("12\n3x\n", |_, ch| {
+            ch.to_digit(10).ok_or_else(|| Error::new("not a digit"))
+        })
+        .unwrap_err();
+
+        assert_eq!((err.line, err.column), (Some(2), Some(2)));
+    }
+}
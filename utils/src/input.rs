@@ -0,0 +1,179 @@
+use std::fmt;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+use crate::error::{Error, Result};
+use crate::grid::Grid;
+use crate::sections::{sections, Sections};
+use crate::span::{lines, Span};
+use crate::{input_name, read_input};
+
+/// A whole input file, loaded once. Lines, sections and grids are all handed
+/// out as views into it, so parsing does not allocate per line.
+///
+/// With the `mmap` feature, files are memory-mapped rather than read. The
+/// input must then not be modified while it is loaded.
+pub struct Input {
+    path: PathBuf,
+    text: Text,
+}
+
+enum Text {
+    Owned(String),
+    #[cfg(feature = "mmap")]
+    Mapped(memmap2::Mmap),
+}
+
+impl Input {
+    /// Loads the file at `path`, or stdin when `path` is `-`.
+    pub fn open<P>(path: P) -> Result<Input>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+
+        #[cfg(feature = "mmap")]
+        if path != Path::new(crate::STDIN) {
+            if let Some(input) = Input::map(path)? {
+                return Ok(input);
+            }
+        }
+
+        Ok(Input {
+            path: path.to_path_buf(),
+            text: Text::Owned(read_input(path)?),
+        })
+    }
+
+    /// Wraps text that is already in memory, e.g. a generated input. `path`
+    /// is only used to name it in errors.
+    pub fn from_string(path: impl Into<PathBuf>, text: String) -> Input {
+        Input {
+            path: path.into(),
+            text: Text::Owned(text),
+        }
+    }
+
+    /// Wraps raw bytes, e.g. from a fuzzer, which must be valid UTF-8.
+    pub fn from_bytes(path: impl Into<PathBuf>, bytes: Vec<u8>) -> Result<Input> {
+        let path = path.into();
+        validate_utf8(&bytes).map_err(|err| err.in_file(input_name(&path)))?;
+        let text = String::from_utf8(bytes).expect("validated above");
+
+        Ok(Input {
+            path,
+            text: Text::Owned(text),
+        })
+    }
+
+    /// Maps the file at `path`, or returns `None` when it is empty, since an
+    /// empty file cannot be mapped everywhere.
+    #[cfg(feature = "mmap")]
+    fn map(path: &Path) -> Result<Option<Input>> {
+        let io_error = |err: std::io::Error| Error::new(err.to_string()).in_file(path);
+
+        let file = std::fs::File::open(path).map_err(io_error)?;
+        if file.metadata().map_err(io_error)?.len() == 0 {
+            return Ok(None);
+        }
+
+        // SAFETY: the mapping is read-only and inputs are not modified while
+        // they are being solved.
+        let map = unsafe { memmap2::Mmap::map(&file) }.map_err(io_error)?;
+        validate_utf8(&map).map_err(|err| err.in_file(path))?;
+
+        Ok(Some(Input {
+            path: path.to_path_buf(),
+            text: Text::Mapped(map),
+        }))
+    }
+
+    /// Where the input was loaded from, as it should be named in errors.
+    pub fn path(&self) -> &Path {
+        input_name(&self.path)
+    }
+
+    pub fn as_str(&self) -> &str {
+        match &self.text {
+            Text::Owned(text) => text,
+            #[cfg(feature = "mmap")]
+            // SAFETY: validated when the file was mapped.
+            Text::Mapped(map) => unsafe { std::str::from_utf8_unchecked(map) },
+        }
+    }
+
+    pub fn lines(&self) -> impl Iterator<Item = Span<'_>> {
+        lines(self.as_str())
+    }
+
+    pub fn sections(&self) -> Sections<'_> {
+        sections(self.as_str())
+    }
+
+    /// The input as a grid of characters, see [`Grid::from_text`].
+    pub fn grid(&self) -> Result<Grid<char>> {
+        Grid::from_text(self.as_str()).map_err(|err| err.in_file(self.path()))
+    }
+}
+
+/// Checks that `bytes` are UTF-8, pointing at the first invalid byte if not.
+fn validate_utf8(bytes: &[u8]) -> Result<()> {
+    let err = match std::str::from_utf8(bytes) {
+        Ok(_) => return Ok(()),
+        Err(err) => err,
+    };
+
+    // everything before the invalid byte is valid, so it can be counted in
+    // chars like the rest of the positions
+    let valid = std::str::from_utf8(&bytes[..err.valid_up_to()]).expect("prefix is valid");
+    let line = valid.matches('\n').count() + 1;
+    let line_start = valid.rfind('\n').map_or(0, |idx| idx + 1);
+    let column = valid[line_start..].chars().count() + 1;
+
+    Err(Error::new("input is not valid UTF-8").at(line, column))
+}
+
+impl Deref for Input {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for Input {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Debug for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Input")
+            .field("path", &self.path)
+            .field("len", &self.as_str().len())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::STDIN;
+
+    #[test]
+    fn invalid_utf8_is_located_in_characters() {
+        let bytes = b"ab\n\xc3\xa9\xc3\xa9x\xffy\n".to_vec();
+        let err = Input::from_bytes("inputs/1/input.txt", bytes).unwrap_err();
+
+        assert_eq!(err.to_string(), "inputs/1/input.txt:2:4: input is not valid UTF-8");
+    }
+
+    #[test]
+    fn valid_bytes_are_kept() {
+        let input = Input::from_bytes(STDIN, "é\n".as_bytes().to_vec()).unwrap();
+
+        assert_eq!(input.as_str(), "é\n");
+        assert_eq!(input.path(), Path::new("<stdin>"));
+    }
+}
@@ -1,15 +1,125 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::{Path, PathBuf}; 
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 
+#[cfg(feature = "alloc-stats")]
+pub mod alloc;
+mod answers;
+mod cache;
+pub mod config;
+mod error;
+#[cfg(feature = "generate")]
+pub mod generate;
+mod grid;
+mod input;
+pub mod log;
+pub mod parse;
+mod registry;
+mod sections;
+mod solver;
+mod span;
+
+pub use answers::Answers;
+pub use cache::{Cache, Key};
+pub use error::{Error, Result};
+pub use grid::{Grid, Position, Run};
+pub use input::Input;
+pub use registry::{DynSolver, Registry};
+pub use sections::{sections, Section, Sections};
+pub use solver::{Diagnostic, Part, Solver, Value};
+pub use span::{lines, Span};
+
+/// Path of `file` (default `input.txt`) for `day` under the input root, see
+/// [`config::input_root`]. A file of `-` stands for stdin.
 pub fn get_input_path(day: u8, file: Option<&str>) -> PathBuf {
-    PathBuf::from(format!("inputs/{}/{}", day, file.unwrap_or("input.txt")))
+    match file {
+        Some(STDIN) => PathBuf::from(STDIN),
+        file => config::input_root()
+            .join(day.to_string())
+            .join(file.unwrap_or("input.txt")),
+    }
+}
+
+/// The path that [`read_input`] reads from stdin.
+pub const STDIN: &str = "-";
+
+/// How `path` is named in error messages: `<stdin>` for `-`, else itself.
+pub fn input_name(path: &Path) -> &Path {
+    if path == Path::new(STDIN) {
+        Path::new("<stdin>")
+    } else {
+        path
+    }
 }
 
-pub fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
+/// Reads a whole input file, or stdin when `path` is `-`, reporting a
+/// missing or unreadable file as an [`Error`] naming it.
+pub fn read_input<P>(path: P) -> Result<String>
 where P: AsRef<Path>, {
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+    let path = path.as_ref();
+
+    if path == Path::new(STDIN) {
+        let mut input = String::new();
+        return io::stdin()
+            .read_to_string(&mut input)
+            .map(|_| input)
+            .map_err(|err| Error::new(err.to_string()).in_file(input_name(path)));
+    }
+
+    fs::read_to_string(path).map_err(|err| Error::new(err.to_string()).in_file(path))
+}
+
+/// Loads the input at `path` and solves one part of it. Errors from parsing or
+/// solving are reported against `path`.
+pub fn solve_file<S, P>(solver: &S, part: Part, path: P) -> Result<S::Answer>
+where
+    S: Solver,
+    P: AsRef<Path>,
+{
+    let input = Input::open(path)?;
+
+    solver
+        .parse(&input)
+        .and_then(|parsed| solver.solve(&parsed, part))
+        .map_err(|err| err.in_file(input.path()))
+}
+
+/// The `main` of a day's binary: solves the part given as first argument, or
+/// every part of the day, on the day's `input.txt`.
+pub fn run_main<S: Solver>(solver: &S) -> ExitCode {
+    log::init(0);
+
+    match run_parts(solver, env::args().nth(1)) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+fn run_parts<S: Solver>(solver: &S, part: Option<String>) -> Result<()> {
+    let parts = match part {
+        Some(part) => vec![part.parse::<Part>().map_err(Error::new)?],
+        None => S::PARTS.to_vec(),
+    };
+    if let Some(part) = parts.iter().find(|part| !S::PARTS.contains(part)) {
+        return Err(Error::new(format!("day {} does not solve part {}", S::DAY, part)));
+    }
+
+    let input = Input::open(get_input_path(S::DAY, Some("input.txt")))?;
+    let parsed = solver.parse(&input).map_err(|err| err.in_file(input.path()))?;
+
+    for part in parts {
+        let answer = solver
+            .solve(&parsed, part)
+            .map_err(|err| err.in_file(input.path()))?;
+        println!("Part {}: {}", part, answer);
+    }
+
+    Ok(())
 }
 
 // #[cfg(test)]
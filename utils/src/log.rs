@@ -0,0 +1,37 @@
+//! Leveled logging for the solutions, on stderr so it never mixes with the
+//! answers.
+//!
+//! Solutions log through the re-exported macros, e.g. `log::debug!(...)`;
+//! nothing is shown unless a binary calls [`init`] with a high enough
+//! verbosity or `AOC_LOG` asks for it. Arguments of disabled levels are not
+//! even evaluated, so logging costs nothing on real runs.
+
+use std::io::{self, IsTerminal};
+
+use tracing_subscriber::EnvFilter;
+
+pub use tracing::{debug, error, info, trace, warn};
+
+/// Overrides the verbosity with a filter such as `debug` or `ex5=trace`.
+pub const LOG_VAR: &str = "AOC_LOG";
+
+/// Installs the stderr logger. `verbosity` is the number of `-v` flags: none
+/// shows warnings and errors, then info, debug and trace. `AOC_LOG`, when set,
+/// takes precedence. Calling it more than once keeps the first logger.
+pub fn init(verbosity: u8) {
+    let level = match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    };
+
+    let filter = EnvFilter::try_from_env(LOG_VAR).unwrap_or_else(|_| EnvFilter::new(level));
+
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .without_time()
+        .try_init();
+}
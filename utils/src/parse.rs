@@ -0,0 +1,189 @@
+//! Combinators for the line shapes that keep coming back in the puzzles.
+//!
+//! They all work on [`Span`]s, so a failure points at the exact line and
+//! column of the offending piece.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::error::Result;
+use crate::span::Span;
+
+/// Whitespace-separated numbers, e.g. `41 48 83  86`.
+pub fn numbers<T>(span: Span) -> Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    span.split_whitespace().map(|number| number.parse::<T>()).collect()
+}
+
+/// Exactly `N` whitespace-separated numbers, e.g. `50 98 2`.
+pub fn numbers_array<T, const N: usize>(span: Span) -> Result<[T; N]>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let numbers = numbers::<T>(span)?;
+    let found = numbers.len();
+
+    numbers
+        .try_into()
+        .map_err(|_| span.error(format!("expected {} numbers, found {}", N, found)))
+}
+
+/// `label: rest`, split on the first colon with both sides trimmed.
+pub fn labeled(line: Span) -> Result<(Span, Span)> {
+    let (label, rest) = line.split_once(":")?;
+
+    Ok((label.trim(), rest.trim()))
+}
+
+/// `label: 1 2 3`, e.g. `Time:      7  15   30`.
+pub fn labeled_numbers<T>(line: Span) -> Result<(Span, Vec<T>)>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let (label, rest) = labeled(line)?;
+
+    Ok((label, numbers(rest)?))
+}
+
+/// `name N: rest`, e.g. `Game 12: ...` or `Card  3: ...`, returning `N` and
+/// the part after the colon.
+pub fn numbered<'a, T>(line: Span<'a>, name: &str) -> Result<(T, Span<'a>)>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let (label, rest) = labeled(line)?;
+    let number = label.strip_prefix(name)?.trim().parse::<T>()?;
+
+    Ok((number, rest))
+}
+
+/// `key = (left, right)`, e.g. `AAA = (BBB, CCC)`.
+pub fn key_pair(line: Span) -> Result<(Span, Span, Span)> {
+    let (key, pair) = line.split_once("=")?;
+    let (left, right) = pair
+        .trim()
+        .strip_prefix("(")?
+        .strip_suffix(")")?
+        .split_once(",")?;
+
+    Ok((key.trim(), left.trim(), right.trim()))
+}
+
+/// `N name`, e.g. `3 blue`.
+pub fn count<T>(item: Span) -> Result<(T, Span)>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let (count, name) = item.trim().split_once(" ")?;
+
+    Ok((count.parse::<T>()?, name.trim()))
+}
+
+/// A list of `N name` items, e.g. `3 blue, 4 red`.
+pub fn counts<'a, T>(list: Span<'a>, separator: &'a str) -> Result<Vec<(T, Span<'a>)>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    list.split(separator).map(count).collect()
+}
+
+/// A two-level list such as `3 blue, 4 red; 1 red, 2 green`, split on
+/// `outer` first and then on `inner`. Every item is trimmed.
+pub fn nested_list<'a>(list: Span<'a>, outer: &'a str, inner: &'a str) -> Vec<Vec<Span<'a>>> {
+    list.split(outer)
+        .map(|group| group.split(inner).map(|item| item.trim()).collect())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line(text: &str) -> Span<'_> {
+        Span::new(text, 1, 1)
+    }
+
+    fn texts(spans: &[Span]) -> Vec<String> {
+        spans.iter().map(|span| span.to_string()).collect()
+    }
+
+    #[test]
+    fn numbers_are_split_on_any_whitespace() {
+        assert_eq!(numbers::<u32>(line("41 48 83  86")).unwrap(), [41, 48, 83, 86]);
+        assert_eq!(numbers::<i64>(line("")).unwrap(), []);
+
+        let err = numbers::<u32>(line("1 2 é3")).unwrap_err();
+        assert_eq!(err.column, Some(5));
+    }
+
+    #[test]
+    fn numbers_array_wants_exactly_n_numbers() {
+        assert_eq!(numbers_array::<u64, 3>(line("50 98 2")).unwrap(), [50, 98, 2]);
+
+        let err = numbers_array::<u64, 3>(line("50 98")).unwrap_err();
+        assert_eq!(err.message, "expected 3 numbers, found 2");
+    }
+
+    #[test]
+    fn labeled_trims_both_sides() {
+        let (label, rest) = labeled(line("Time:      7  15   30")).unwrap();
+        assert_eq!((label.as_str(), rest.as_str(), rest.column()), ("Time", "7  15   30", 12));
+
+        let (label, numbers) = labeled_numbers::<u32>(line("Distance:  9  40")).unwrap();
+        assert_eq!((label.as_str(), numbers), ("Distance", vec![9, 40]));
+    }
+
+    #[test]
+    fn numbered_reads_the_number_after_the_name() {
+        let (id, rest) = numbered::<u32>(line("Card  3: 1 21 | 5"), "Card").unwrap();
+        assert_eq!((id, rest.as_str(), rest.column()), (3, "1 21 | 5", 10));
+
+        let err = numbered::<u32>(line("Card x: 1"), "Card").unwrap_err();
+        assert_eq!(err.column, Some(6));
+
+        let err = numbered::<u32>(line("Game 1: 1"), "Card").unwrap_err();
+        assert_eq!(err.message, "expected 'Card' at 'Game 1'");
+    }
+
+    #[test]
+    fn key_pair_splits_the_parenthesized_pair() {
+        let (key, left, right) = key_pair(line("AAA = (BBB, CCC)")).unwrap();
+        assert_eq!(texts(&[key, left, right]), ["AAA", "BBB", "CCC"]);
+        assert_eq!(right.column(), 13);
+
+        let err = key_pair(line("AAA = BBB, CCC)")).unwrap_err();
+        assert_eq!(err.message, "expected '(' at 'BBB, CCC)'");
+        assert_eq!(err.column, Some(7));
+    }
+
+    #[test]
+    fn counts_pair_numbers_with_names() {
+        let (n, name) = count::<u32>(line(" 3 blue")).unwrap();
+        assert_eq!((n, name.as_str(), name.column()), (3, "blue", 4));
+
+        let items = counts::<u32>(line("3 blue, 4 red"), ",").unwrap();
+        let items: Vec<_> = items.iter().map(|(n, name)| (*n, name.as_str(), name.column())).collect();
+        assert_eq!(items, [(3, "blue", 3), (4, "red", 11)]);
+
+        let err = counts::<u32>(line("3 blue, red"), ",").unwrap_err();
+        assert_eq!(err.column, Some(9));
+    }
+
+    #[test]
+    fn nested_list_splits_outer_then_inner() {
+        let groups = nested_list(line("3 blue, 4 red; 1 ü"), ";", ",");
+        let groups: Vec<_> = groups.iter().map(|group| texts(group)).collect();
+        assert_eq!(groups, [vec!["3 blue", "4 red"], vec!["1 ü"]]);
+
+        let last = nested_list(line("a; ü, b"), ";", ",")[1][1];
+        assert_eq!((last.as_str(), last.column()), ("b", 7));
+    }
+}
@@ -0,0 +1,126 @@
+use std::any::Any;
+
+use crate::error::{Error, Result};
+use crate::solver::{Diagnostic, Part, Solver};
+
+/// Type-erased view of a [`Solver`], so solvers with different input and
+/// answer types can live in the same [`Registry`].
+pub trait DynSolver: Send + Sync {
+    fn day(&self) -> u8;
+
+    fn parts(&self) -> &'static [Part];
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>>;
+
+    /// Solves `part` on a value previously returned by [`DynSolver::parse`].
+    fn solve(&self, parsed: &dyn Any, part: Part) -> Result<String>;
+
+    /// See [`Solver::diagnostics`].
+    fn diagnostics(&self, parsed: &dyn Any, part: Part) -> Result<Vec<Diagnostic>>;
+}
+
+impl<S> DynSolver for S
+where
+    S: Solver + Send + Sync,
+    S::Input: 'static,
+{
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn parse(&self, input: &str) -> Result<Box<dyn Any>> {
+        let parsed = Solver::parse(self, input)?;
+        Ok(Box::new(parsed))
+    }
+
+    fn solve(&self, parsed: &dyn Any, part: Part) -> Result<String> {
+        Solver::solve(self, downcast::<S>(parsed), part).map(|answer| answer.to_string())
+    }
+
+    fn diagnostics(&self, parsed: &dyn Any, part: Part) -> Result<Vec<Diagnostic>> {
+        Solver::diagnostics(self, downcast::<S>(parsed), part)
+    }
+}
+
+fn downcast<S>(parsed: &dyn Any) -> &S::Input
+where
+    S: Solver,
+    S::Input: 'static,
+{
+    parsed
+        .downcast_ref::<S::Input>()
+        .expect("parsed input does not belong to this solver")
+}
+
+/// Every known solver, indexed by day and part.
+#[derive(Default)]
+pub struct Registry {
+    solvers: Vec<Box<dyn DynSolver>>,
+}
+
+impl Registry {
+    pub fn new() -> Registry {
+        Registry::default()
+    }
+
+    /// Adds a solver for the parts it declares. Panics if one of those parts
+    /// is already provided by another solver.
+    pub fn register<S>(&mut self, solver: S) -> &mut Registry
+    where
+        S: Solver + Send + Sync + 'static,
+        S::Input: 'static,
+    {
+        for &part in S::PARTS {
+            assert!(
+                self.get(S::DAY, part).is_none(),
+                "day {} part {} is registered twice",
+                S::DAY,
+                part
+            );
+        }
+
+        self.solvers.push(Box::new(solver));
+        self
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&dyn DynSolver> {
+        self.solvers
+            .iter()
+            .find(|solver| solver.day() == day && solver.parts().contains(&part))
+            .map(|solver| solver.as_ref())
+    }
+
+    /// All registered `(day, part)` pairs, in calendar order.
+    pub fn entries(&self) -> Vec<(u8, Part)> {
+        let mut entries: Vec<(u8, Part)> = self
+            .solvers
+            .iter()
+            .flat_map(|solver| solver.parts().iter().map(|&part| (solver.day(), part)))
+            .collect();
+
+        entries.sort();
+        entries
+    }
+
+    pub fn days(&self) -> Vec<u8> {
+        let mut days: Vec<u8> = self.solvers.iter().map(|solver| solver.day()).collect();
+
+        days.sort();
+        days.dedup();
+        days
+    }
+
+    /// Parses `input` and solves `part` of `day`.
+    pub fn solve(&self, day: u8, part: Part, input: &str) -> Result<String> {
+        let solver = self.get(day, part).ok_or_else(|| {
+            Error::new(format!("no solution registered for day {} part {}", day, part))
+        })?;
+        let parsed = solver.parse(input)?;
+
+        solver.solve(parsed.as_ref(), part)
+    }
+}
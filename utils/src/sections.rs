@@ -0,0 +1,158 @@
+use crate::error::{Error, Result};
+use crate::span::{lines, Span};
+
+/// A block of consecutive non-blank lines.
+///
+/// When the first line of the block is a header such as `seed-to-soil map:`
+/// the block is named after it, and the header is not part of `lines`. Any
+/// line ending in its only colon is a header, so a `seeds:` line without
+/// numbers is one too.
+#[derive(Clone, Debug)]
+pub struct Section<'a> {
+    name: Option<Span<'a>>,
+    lines: Vec<Span<'a>>,
+}
+
+/// The blocks of an input, in order.
+#[derive(Clone, Debug)]
+pub struct Sections<'a> {
+    sections: Vec<Section<'a>>,
+}
+
+/// Splits `input` into blocks separated by one or more blank lines. Leading
+/// and trailing blank lines, whitespace-only lines and `\r\n` endings are all
+/// tolerated.
+pub fn sections(input: &str) -> Sections<'_> {
+    let mut sections: Vec<Section> = Vec::new();
+    let mut curr_lines: Vec<Span> = Vec::new();
+
+    for line in lines(input) {
+        if !line.trim().is_empty() {
+            curr_lines.push(line);
+        } else if !curr_lines.is_empty() {
+            sections.push(Section::new(std::mem::take(&mut curr_lines)));
+        }
+    }
+    // push the last section
+    if !curr_lines.is_empty() {
+        sections.push(Section::new(curr_lines));
+    }
+
+    Sections { sections }
+}
+
+impl<'a> Section<'a> {
+    fn new(mut lines: Vec<Span<'a>>) -> Section<'a> {
+        let header = lines[0]
+            .as_str()
+            .trim_end()
+            .strip_suffix(':')
+            .filter(|header| !header.contains(':'));
+
+        let name = header.map(|header| Span::new(header, lines[0].line(), lines[0].column()));
+        if name.is_some() {
+            lines.remove(0);
+        }
+
+        Section { name, lines }
+    }
+
+    /// The header of the section, without the trailing colon.
+    pub fn name(&self) -> Option<&'a str> {
+        self.name.map(|name| name.as_str())
+    }
+
+    pub fn lines(&self) -> &[Span<'a>] {
+        &self.lines
+    }
+
+    pub fn line(&self, idx: usize) -> Result<Span<'a>> {
+        self.lines.get(idx).copied().ok_or_else(|| {
+            let start = self.name.unwrap_or_else(|| self.lines[0]);
+            start.error(format!("expected at least {} lines in this section", idx + 1))
+        })
+    }
+}
+
+impl<'a> Sections<'a> {
+    pub fn len(&self) -> usize {
+        self.sections.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Section<'a>> {
+        self.sections.iter()
+    }
+
+    pub fn get(&self, idx: usize) -> Result<&Section<'a>> {
+        self.sections.get(idx).ok_or_else(|| {
+            Error::new(format!(
+                "expected at least {} sections, found {}",
+                idx + 1,
+                self.sections.len()
+            ))
+        })
+    }
+
+    /// The section whose header is `name:`.
+    pub fn named(&self, name: &str) -> Result<&Section<'a>> {
+        self.sections
+            .iter()
+            .find(|section| section.name() == Some(name))
+            .ok_or_else(|| Error::new(format!("missing section '{}:'", name)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts<'a>(section: &Section<'a>) -> Vec<&'a str> {
+        section.lines().iter().map(|line| line.as_str()).collect()
+    }
+
+    #[test]
+    fn blank_and_whitespace_lines_separate_sections() {
+        let sections = sections("\n\na\nb\n \t\n\n c\n\n\n");
+
+        assert_eq!(sections.len(), 2);
+        assert_eq!(texts(sections.get(0).unwrap()), ["a", "b"]);
+        assert_eq!(texts(sections.get(1).unwrap()), [" c"]);
+        assert_eq!(sections.get(1).unwrap().lines()[0].line(), 7);
+        assert!(sections.get(2).is_err());
+    }
+
+    #[test]
+    fn crlf_endings_are_accepted() {
+        let sections = sections("seeds: 1 2\r\n\r\nsoil map:\r\n3 4 5\r\n");
+
+        assert_eq!(texts(sections.get(0).unwrap()), ["seeds: 1 2"]);
+        assert_eq!(texts(sections.named("soil map").unwrap()), ["3 4 5"]);
+    }
+
+    #[test]
+    fn headers_end_with_their_only_colon() {
+        let sections = sections("seeds: 79 14\n\nseed-to-soil map:  \n50 98 2\n\nseeds:\n\nL: R\n");
+
+        let names: Vec<_> = sections.iter().map(|section| section.name()).collect();
+        assert_eq!(names, [None, Some("seed-to-soil map"), Some("seeds"), None]);
+
+        assert_eq!(texts(sections.named("seed-to-soil map").unwrap()), ["50 98 2"]);
+        assert!(sections.named("seeds").unwrap().lines().is_empty());
+        assert_eq!(texts(sections.get(3).unwrap()), ["L: R"]);
+    }
+
+    #[test]
+    fn missing_lines_point_at_the_header() {
+        let sections = sections("a\n\nempty map:\n");
+
+        let err = sections.named("empty map").unwrap().line(0).unwrap_err();
+        assert_eq!((err.line, err.column), (Some(3), Some(1)));
+
+        let err = sections.named("full map").unwrap_err();
+        assert_eq!(err.message, "missing section 'full map:'");
+    }
+}
@@ -0,0 +1,177 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use itertools::Itertools;
+use serde::Serialize;
+
+use crate::error::{Error, Result};
+
+/// One of the two halves of a day's puzzle.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.number())
+    }
+}
+
+impl FromStr for Part {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim() {
+            "1" | "one" => Ok(Part::One),
+            "2" | "two" => Ok(Part::Two),
+            other => Err(format!("invalid part '{}', expected 1 or 2", other)),
+        }
+    }
+}
+
+/// The value of a [`Diagnostic`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Value {
+    Number(i128),
+    Numbers(Vec<i128>),
+    Text(String),
+}
+
+/// A named intermediate value reported next to an answer, e.g. the range size
+/// of every race that went into a product.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub name: &'static str,
+    pub value: Value,
+}
+
+impl Diagnostic {
+    pub fn new(name: &'static str, value: impl Into<Value>) -> Diagnostic {
+        Diagnostic {
+            name,
+            value: value.into(),
+        }
+    }
+}
+
+macro_rules! impl_value_from_integer {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for Value {
+                fn from(number: $int) -> Value {
+                    Value::Number(number as i128)
+                }
+            }
+
+            impl From<Vec<$int>> for Value {
+                fn from(numbers: Vec<$int>) -> Value {
+                    Value::Numbers(numbers.into_iter().map(|number| number as i128).collect())
+                }
+            }
+        )*
+    };
+}
+
+impl_value_from_integer!(i32, i64, u32, u64, usize);
+
+impl From<String> for Value {
+    fn from(text: String) -> Value {
+        Value::Text(text)
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Value::Number(number) => write!(f, "{}", number),
+            Value::Numbers(numbers) => write!(f, "{}", numbers.iter().join(" ")),
+            Value::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+/// A solution for one day of the calendar.
+///
+/// The input is parsed once into `Input` and then handed to each part. A
+/// solver only has to implement the parts listed in `PARTS`; asking it for any
+/// other part is an error.
+///
+/// Malformed input is reported through [`crate::Error`] rather than a panic,
+/// ideally pointing at the offending line and column.
+pub trait Solver {
+    const DAY: u8;
+    const PARTS: &'static [Part] = &Part::ALL;
+
+    type Input;
+    type Answer: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input>;
+
+    fn part_one(&self, _input: &Self::Input) -> Result<Self::Answer> {
+        Err(Error::new(format!("day {} does not solve part one", Self::DAY)))
+    }
+
+    fn part_two(&self, _input: &Self::Input) -> Result<Self::Answer> {
+        Err(Error::new(format!("day {} does not solve part two", Self::DAY)))
+    }
+
+    fn solve(&self, input: &Self::Input, part: Part) -> Result<Self::Answer> {
+        match part {
+            Part::One => self.part_one(input),
+            Part::Two => self.part_two(input),
+        }
+    }
+
+    /// Intermediate values behind the answer of `part`, for structured
+    /// output. Only asked for when they are going to be shown, so they may be
+    /// recomputed here. None by default.
+    fn diagnostics(&self, _input: &Self::Input, _part: Part) -> Result<Vec<Diagnostic>> {
+        Ok(Vec::new())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct PartOne;
+
+    impl Solver for PartOne {
+        const DAY: u8 = 1;
+        const PARTS: &'static [Part] = &[Part::One];
+
+        type Input = u32;
+        type Answer = u32;
+
+        fn parse(&self, input: &str) -> Result<u32> {
+            input.trim().parse().map_err(|_| Error::new("not a number"))
+        }
+
+        fn part_one(&self, input: &u32) -> Result<u32> {
+            Ok(input * 2)
+        }
+    }
+
+    #[test]
+    fn unsolved_parts_are_errors() {
+        let input = PartOne.parse("21").unwrap();
+
+        assert_eq!(PartOne.solve(&input, Part::One).unwrap(), 42);
+        let err = PartOne.solve(&input, Part::Two).unwrap_err();
+        assert_eq!(err.to_string(), "day 1 does not solve part two");
+    }
+}
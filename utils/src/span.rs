@@ -0,0 +1,187 @@
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+use crate::error::{Error, Result};
+
+/// A piece of the input that remembers where it starts, so that parse
+/// failures can be reported at the right line and column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span<'a> {
+    text: &'a str,
+    line: usize,
+    column: usize,
+}
+
+/// Splits `input` into lines, numbered from 1. Both `\n` and `\r\n` line
+/// endings are accepted.
+pub fn lines(input: &str) -> impl Iterator<Item = Span<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| Span::new(line, idx + 1, 1))
+}
+
+impl<'a> Span<'a> {
+    pub fn new(text: &'a str, line: usize, column: usize) -> Span<'a> {
+        Span { text, line, column }
+    }
+
+    pub fn as_str(&self) -> &'a str {
+        self.text
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn column(&self) -> usize {
+        self.column
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+
+    /// An error located at the start of this span.
+    pub fn error(&self, message: impl Into<String>) -> Error {
+        Error::new(message).at(self.line, self.column)
+    }
+
+    /// The sub-span starting `offset` bytes into this one.
+    fn sub(&self, offset: usize, len: usize) -> Span<'a> {
+        Span {
+            text: &self.text[offset..offset + len],
+            line: self.line,
+            column: self.column + self.text[..offset].chars().count(),
+        }
+    }
+
+    /// Wraps a slice borrowed from this span's text.
+    fn piece(&self, piece: &'a str) -> Span<'a> {
+        let offset = piece.as_ptr() as usize - self.text.as_ptr() as usize;
+        self.sub(offset, piece.len())
+    }
+
+    pub fn trim(&self) -> Span<'a> {
+        self.piece(self.text.trim())
+    }
+
+    pub fn split_once(&self, delimiter: &str) -> Result<(Span<'a>, Span<'a>)> {
+        match self.text.find(delimiter) {
+            Some(idx) => {
+                let rest = idx + delimiter.len();
+                Ok((self.sub(0, idx), self.sub(rest, self.text.len() - rest)))
+            }
+            None => Err(self.error(format!("expected '{}' in '{}'", delimiter, self.text))),
+        }
+    }
+
+    pub fn strip_prefix(&self, prefix: &str) -> Result<Span<'a>> {
+        match self.text.strip_prefix(prefix) {
+            Some(rest) => Ok(self.piece(rest)),
+            None => Err(self.error(format!("expected '{}' at '{}'", prefix, self.text))),
+        }
+    }
+
+    pub fn strip_suffix(&self, suffix: &str) -> Result<Span<'a>> {
+        match self.text.strip_suffix(suffix) {
+            Some(rest) => Ok(self.piece(rest)),
+            None => Err(self.error(format!("expected '{}' at the end of '{}'", suffix, self.text))),
+        }
+    }
+
+    pub fn split(&self, delimiter: &'a str) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = *self;
+        self.text.split(delimiter).map(move |piece| span.piece(piece))
+    }
+
+    pub fn split_whitespace(&self) -> impl Iterator<Item = Span<'a>> + 'a {
+        let span = *self;
+        self.text.split_whitespace().map(move |piece| span.piece(piece))
+    }
+
+    /// The characters of the span, each with its own position.
+    pub fn chars(&self) -> impl Iterator<Item = (Span<'a>, char)> + 'a {
+        let span = *self;
+        self.text
+            .char_indices()
+            .map(move |(idx, ch)| (span.sub(idx, ch.len_utf8()), ch))
+    }
+
+    pub fn parse<T>(&self) -> Result<T>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        self.text
+            .parse::<T>()
+            .map_err(|err| self.error(format!("invalid value '{}': {}", self.text, err)))
+    }
+}
+
+impl Display for Span<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(span: Span) -> (usize, usize) {
+        (span.line(), span.column())
+    }
+
+    #[test]
+    fn lines_are_numbered_from_one() {
+        let lines: Vec<_> = lines("a\r\nb\n\nc").map(|line| (line.as_str(), position(line))).collect();
+
+        assert_eq!(lines, [("a", (1, 1)), ("b", (2, 1)), ("", (3, 1)), ("c", (4, 1))]);
+    }
+
+    #[test]
+    fn columns_count_characters_not_bytes() {
+        let line = Span::new("é ü: 42", 3, 1);
+        let (label, rest) = line.split_once(":").unwrap();
+
+        assert_eq!(position(label), (3, 1));
+        assert_eq!(position(rest), (3, 5));
+        assert_eq!(position(rest.trim()), (3, 6));
+
+        let chars: Vec<_> = line.chars().map(|(span, ch)| (ch, span.column())).take(3).collect();
+        assert_eq!(chars, [('é', 1), (' ', 2), ('ü', 3)]);
+    }
+
+    #[test]
+    fn pieces_keep_the_offset_of_their_span() {
+        let line = Span::new("  Game 7 ", 2, 10);
+
+        let game = line.trim();
+        assert_eq!((game.as_str(), position(game)), ("Game 7", (2, 12)));
+
+        let number = game.strip_prefix("Game ").unwrap();
+        assert_eq!((number.as_str(), position(number)), ("7", (2, 17)));
+
+        let words: Vec<_> = line.split_whitespace().map(|word| word.column()).collect();
+        assert_eq!(words, [12, 17]);
+
+        let pieces: Vec<_> = Span::new("a,bé,c", 1, 1).split(",").map(|piece| piece.column()).collect();
+        assert_eq!(pieces, [1, 3, 6]);
+    }
+
+    #[test]
+    fn errors_point_at_the_start_of_the_span() {
+        let line = Span::new("x = ü7", 4, 1);
+        let (_, value) = line.split_once("= ").unwrap();
+
+        let err = value.parse::<u32>().unwrap_err();
+        assert_eq!((err.line, err.column), (Some(4), Some(5)));
+
+        let err = value.strip_suffix(")").unwrap_err();
+        assert_eq!(err.message, "expected ')' at the end of 'ü7'");
+
+        let err = line.split_once(";").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(4), Some(1)));
+    }
+}